@@ -0,0 +1,238 @@
+//! Known answers for the bundled inputs, checked in as `answers.txt` next to
+//! each day's `input.txt` and embedded in the binary along with it:
+//!
+//! ```text
+//! part1: 1215
+//! part2:
+//! | multi-line answers go below an empty `partN:`,
+//! | each of their lines being prefixed with `| `
+//! ```
+
+use crate::error::Error;
+use crate::solution::{Answer, DynSolution, Part};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+impl Answers {
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+
+    fn get_mut(&mut self, part: Part) -> &mut Option<String> {
+        match part {
+            Part::One => &mut self.part1,
+            Part::Two => &mut self.part2,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseAnswersError {
+    /// 1-based line of the answers file.
+    pub line: usize,
+    pub reason: &'static str,
+}
+
+impl Display for ParseAnswersError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.reason)
+    }
+}
+
+impl std::error::Error for ParseAnswersError {}
+
+impl FromStr for Answers {
+    type Err = ParseAnswersError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = Self::default();
+        let mut current: Option<Part> = None;
+
+        for (index, line) in s.lines().enumerate() {
+            let error = |reason| ParseAnswersError {
+                line: index + 1,
+                reason,
+            };
+
+            if let Some(text_line) = line.strip_prefix('|') {
+                let text_line = text_line.strip_prefix(' ').unwrap_or(text_line);
+                let part = current.ok_or_else(|| error("text line outside of an answer"))?;
+                let answer = answers.get_mut(part).get_or_insert_with(String::new);
+
+                if !answer.is_empty() {
+                    answer.push('\n');
+                }
+
+                answer.push_str(text_line);
+
+                continue;
+            }
+
+            if line.trim().is_empty() {
+                current = None;
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once(':')
+                .ok_or_else(|| error("expected `part<N>: <answer>`"))?;
+
+            let part = match key.trim() {
+                "part1" => Part::One,
+                "part2" => Part::Two,
+                _ => return Err(error("unknown part, expected part1 or part2")),
+            };
+
+            let answer = answers.get_mut(part);
+
+            if answer.is_some() {
+                return Err(error("answer given twice"));
+            }
+
+            let value = value.trim();
+
+            if value.is_empty() {
+                current = Some(part);
+            } else {
+                *answer = Some(value.to_string());
+                current = None;
+            }
+        }
+
+        Ok(answers)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Match,
+    Mismatch {
+        expected: String,
+        actual: String,
+    },
+    /// The part is solved but no answer was recorded for it.
+    Unrecorded {
+        actual: String,
+    },
+    /// The part is not solved yet.
+    Unchecked,
+    Failed(Error),
+}
+
+impl Outcome {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Self::Mismatch { .. } | Self::Failed(_))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Check {
+    pub day: u8,
+    pub part: Part,
+    pub outcome: Outcome,
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "Day {}, part {}: ", self.day, self.part)?;
+
+        match &self.outcome {
+            Outcome::Match => write!(f, "ok"),
+            Outcome::Mismatch { expected, actual } => {
+                write!(f, "MISMATCH, expected {:?} got {:?}", expected, actual)
+            }
+            Outcome::Unrecorded { actual } => write!(f, "no recorded answer, got {:?}", actual),
+            Outcome::Unchecked => write!(f, "not checked"),
+            Outcome::Failed(err) => write!(f, "FAILED, {}", err),
+        }
+    }
+}
+
+/// Solves `parts` of the bundled input of `solution` and compares the results
+/// with its recorded answers.
+pub fn check(solution: &dyn DynSolution, parts: &[Part]) -> Result<Vec<Check>, ParseAnswersError> {
+    let day = solution.day();
+    let answers: Answers = solution.answers().parse()?;
+
+    let results: Vec<_> = match solution.run(solution.input(), parts) {
+        Ok(runs) => runs.into_iter().map(|run| (run.part, run.answer)).collect(),
+        Err(err) => parts.iter().map(|part| (*part, Err(err.clone()))).collect(),
+    };
+
+    Ok(results
+        .into_iter()
+        .map(|(part, answer)| {
+            let outcome = match answer {
+                Err(err) => Outcome::Failed(err),
+                Ok(Answer::Unsolved) => Outcome::Unchecked,
+                Ok(actual) => match answers.get(part) {
+                    None => Outcome::Unrecorded {
+                        actual: actual.to_string(),
+                    },
+                    Some(expected) if actual.to_string() == expected => Outcome::Match,
+                    Some(expected) => Outcome::Mismatch {
+                        expected: expected.to_string(),
+                        actual: actual.to_string(),
+                    },
+                },
+            };
+
+            Check { day, part, outcome }
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    #[test]
+    fn parse_answers() {
+        let answers: Answers = "part1: 42\n\npart2:\n|  # #\n|# \n".parse().unwrap();
+
+        assert_eq!(answers.get(Part::One), Some("42"));
+        assert_eq!(answers.get(Part::Two), Some(" # #\n# "));
+
+        let answers: Answers = "part2: 7\n".parse().unwrap();
+
+        assert_eq!(answers.get(Part::One), None);
+        assert_eq!(answers.get(Part::Two), Some("7"));
+    }
+
+    #[test]
+    fn parse_invalid_answers() {
+        assert_eq!(
+            "part1: 1\npart3: 2\n".parse::<Answers>(),
+            Err(ParseAnswersError {
+                line: 2,
+                reason: "unknown part, expected part1 or part2"
+            })
+        );
+        assert!("part1: 1\npart1: 2\n".parse::<Answers>().is_err());
+        assert!("| orphan\n".parse::<Answers>().is_err());
+        assert!("42\n".parse::<Answers>().is_err());
+    }
+
+    #[test]
+    fn bundled_answers() {
+        for solution in days::all() {
+            for check in check(solution, &Part::ALL).unwrap() {
+                assert!(
+                    matches!(check.outcome, Outcome::Match | Outcome::Unchecked),
+                    "{}",
+                    check
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,349 @@
+use crate::error;
+use crate::json;
+use crate::solution::{Answer, Part, Solution};
+use std::fmt::{Display, Formatter};
+use std::hint::black_box;
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+/// What is being timed for a day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Task {
+    Parse,
+    Solve {
+        part: Part,
+        /// `None` for the day's own `part1`/`part2`.
+        variant: Option<&'static str>,
+    },
+}
+
+impl Display for Task {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Self::Parse => write!(f, "parse"),
+            Self::Solve {
+                part,
+                variant: None,
+            } => write!(f, "part {}", part),
+            Self::Solve {
+                part,
+                variant: Some(variant),
+            } => write!(f, "part {} ({})", part, variant),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Stats {
+    /// Panics when `samples` is empty.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "Cannot compute stats without samples");
+
+        samples.sort_unstable();
+
+        let runs = samples.len();
+        let median = if runs.is_multiple_of(2) {
+            (samples[runs / 2 - 1] + samples[runs / 2]) / 2
+        } else {
+            samples[runs / 2]
+        };
+        let mean = samples.iter().sum::<Duration>() / runs as u32;
+
+        Self {
+            runs,
+            min: samples[0],
+            median,
+            mean,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Measure {
+    pub day: u8,
+    pub task: Task,
+    /// Result of the task, `None` when parsing.
+    pub answer: Option<Answer>,
+    pub stats: Stats,
+}
+
+/// Times parsing, both parts and every variant of `S`, each of them `runs`
+/// times. Solving is timed on an already parsed input, and unsolved parts are
+/// left out.
+///
+/// Fails as soon as parsing or one of the solvers fails.
+pub fn bench<S: Solution>(input: &str, runs: usize) -> error::Result<Vec<Measure>> {
+    let runs = runs.max(1);
+    let parsed = S::parse(input)?;
+
+    let mut measures = vec![Measure {
+        day: S::DAY,
+        task: Task::Parse,
+        answer: None,
+        stats: time(runs, || S::parse(input)),
+    }];
+
+    let solvers = [
+        (
+            Part::One,
+            None,
+            S::part1 as fn(&S::Input) -> error::Result<Answer>,
+        ),
+        (Part::Two, None, S::part2),
+    ]
+    .into_iter()
+    .chain(
+        S::variants()
+            .into_iter()
+            .map(|variant| (variant.part, Some(variant.name), variant.solve)),
+    );
+
+    for (part, variant, solve) in solvers {
+        let answer = solve(&parsed)?;
+
+        if answer == Answer::Unsolved {
+            continue;
+        }
+
+        measures.push(Measure {
+            day: S::DAY,
+            task: Task::Solve { part, variant },
+            answer: Some(answer),
+            stats: time(runs, || solve(&parsed)),
+        });
+    }
+
+    Ok(measures)
+}
+
+fn time<T, F>(runs: usize, mut f: F) -> Stats
+where
+    F: FnMut() -> T,
+{
+    let samples = (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+
+    Stats::from_samples(samples)
+}
+
+pub fn write_text<W: Write>(mut w: W, measures: &[Measure]) -> io::Result<()> {
+    let mut current_day = None;
+
+    for measure in measures {
+        if current_day != Some(measure.day) {
+            if current_day.is_some() {
+                writeln!(w)?;
+            }
+
+            writeln!(w, "Day {} ({} runs):", measure.day, measure.stats.runs)?;
+            current_day = Some(measure.day);
+        }
+
+        write!(
+            w,
+            "\t{:<16} min {:>12}  median {:>12}  mean {:>12}",
+            measure.task.to_string(),
+            format!("{:?}", measure.stats.min),
+            format!("{:?}", measure.stats.median),
+            format!("{:?}", measure.stats.mean),
+        )?;
+
+        match &measure.answer {
+            Some(Answer::Text(_)) | None => writeln!(w)?,
+            Some(answer) => writeln!(w, "  => {}", answer)?,
+        }
+    }
+
+    Ok(())
+}
+
+pub fn write_csv<W: Write>(mut w: W, measures: &[Measure]) -> io::Result<()> {
+    writeln!(
+        w,
+        "day,task,part,variant,runs,min_ns,median_ns,mean_ns,answer"
+    )?;
+
+    for measure in measures {
+        let (task, part, variant) = match measure.task {
+            Task::Parse => ("parse", String::new(), ""),
+            Task::Solve { part, variant } => ("solve", part.to_string(), variant.unwrap_or("")),
+        };
+
+        writeln!(
+            w,
+            "{},{},{},{},{},{},{},{},{}",
+            measure.day,
+            task,
+            part,
+            variant,
+            measure.stats.runs,
+            measure.stats.min.as_nanos(),
+            measure.stats.median.as_nanos(),
+            measure.stats.mean.as_nanos(),
+            measure
+                .answer
+                .as_ref()
+                .map(|answer| csv_field(&answer.to_string()))
+                .unwrap_or_default(),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Writes one JSON object per measure and line.
+pub fn write_json<W: Write>(mut w: W, measures: &[Measure]) -> io::Result<()> {
+    for measure in measures {
+        let (task, part, variant) = match measure.task {
+            Task::Parse => ("parse", "null".to_string(), "null".to_string()),
+            Task::Solve { part, variant } => (
+                "solve",
+                part.to_string(),
+                variant.map_or("null".to_string(), json::string),
+            ),
+        };
+
+        writeln!(
+            w,
+            "{{\"day\":{},\"task\":\"{}\",\"part\":{},\"variant\":{},\"runs\":{},\"min_ns\":{},\"median_ns\":{},\"mean_ns\":{},\"answer\":{}}}",
+            measure.day,
+            task,
+            part,
+            variant,
+            measure.stats.runs,
+            measure.stats.min.as_nanos(),
+            measure.stats.median.as_nanos(),
+            measure.stats.mean.as_nanos(),
+            measure
+                .answer
+                .as_ref()
+                .map_or("null".to_string(), json::answer),
+        )?;
+    }
+
+    Ok(())
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains(['"', ',', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::day1::Day1;
+
+    #[test]
+    fn stats_from_samples() {
+        let ms = Duration::from_millis;
+
+        assert_eq!(
+            Stats::from_samples(vec![ms(5), ms(1), ms(3)]),
+            Stats {
+                runs: 3,
+                min: ms(1),
+                median: ms(3),
+                mean: ms(3),
+            }
+        );
+        assert_eq!(
+            Stats::from_samples(vec![ms(8), ms(2), ms(4), ms(2)]),
+            Stats {
+                runs: 4,
+                min: ms(2),
+                median: ms(3),
+                mean: ms(4),
+            }
+        );
+    }
+
+    #[test]
+    fn bench_day() {
+        let measures = bench::<Day1>("1\n2\n3\n4\n", 3).unwrap();
+
+        let tasks: Vec<_> = measures.iter().map(|measure| measure.task).collect();
+        assert_eq!(
+            tasks,
+            vec![
+                Task::Parse,
+                Task::Solve {
+                    part: Part::One,
+                    variant: None
+                },
+                Task::Solve {
+                    part: Part::Two,
+                    variant: None
+                },
+            ]
+        );
+
+        assert!(measures.iter().all(|measure| measure.stats.runs == 3));
+        assert_eq!(measures[1].answer, Some(Answer::Number(3)));
+    }
+
+    fn measures() -> [Measure; 2] {
+        let stats = Stats::from_samples(vec![Duration::from_nanos(42)]);
+
+        [
+            Measure {
+                day: 7,
+                task: Task::Parse,
+                answer: None,
+                stats,
+            },
+            Measure {
+                day: 7,
+                task: Task::Solve {
+                    part: Part::Two,
+                    variant: Some("algo2"),
+                },
+                answer: Some(Answer::Number(168)),
+                stats,
+            },
+        ]
+    }
+
+    #[test]
+    fn csv_report() {
+        let mut csv = Vec::new();
+        write_csv(&mut csv, &measures()).unwrap();
+
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "\
+day,task,part,variant,runs,min_ns,median_ns,mean_ns,answer
+7,parse,,,1,42,42,42,
+7,solve,2,algo2,1,42,42,42,168
+"
+        );
+    }
+
+    #[test]
+    fn json_report() {
+        let mut json = Vec::new();
+        write_json(&mut json, &measures()).unwrap();
+
+        assert_eq!(
+            String::from_utf8(json).unwrap(),
+            r#"{"day":7,"task":"parse","part":null,"variant":null,"runs":1,"min_ns":42,"median_ns":42,"mean_ns":42,"answer":null}
+{"day":7,"task":"solve","part":2,"variant":"algo2","runs":1,"min_ns":42,"median_ns":42,"mean_ns":42,"answer":168}
+"#
+        );
+    }
+}
@@ -0,0 +1,455 @@
+use aoc2021::answers;
+use aoc2021::bench::{self, Task};
+use aoc2021::days;
+use aoc2021::input::InputSource;
+use aoc2021::json;
+use aoc2021::solution::{Answer, DynSolution, Part, PartRun};
+use std::str::FromStr;
+use std::{env, io, process};
+
+const USAGE: &str = "\
+Usage:
+    aoc --day <DAY> [--part <PART>] [--bench [--runs <N>]] [--format <FORMAT>] [INPUT]
+    aoc --all [--part <PART>] [--bench [--runs <N>]] [--format <FORMAT>]
+    aoc --check [--day <DAY>] [--part <PART>]
+
+Options:
+    -d, --day <DAY>      Day to run
+    -p, --part <PART>    Part to run (1 or 2), both parts when omitted
+    -a, --all            Run every day with its bundled input
+    -b, --bench          Time parsing, parts and their variants instead of
+                         printing the answers
+    -n, --runs <N>       Number of runs per benchmarked task [default: 10]
+    -f, --format <FORMAT>
+                         Output format: text, json, or csv with --bench
+                         [default: text]
+    -c, --check          Compare the answers on the bundled inputs with the
+                         ones recorded in answers.txt, every day by default
+    -h, --help           Print this message
+
+INPUT is the path of the puzzle input, or - to read it from stdin. The input.txt
+bundled with the day's sources is used when omitted.
+
+The json format prints one object per line. Answers are reported as
+{\"day\", \"part\", \"answer\", \"elapsed\"}, elapsed being the solving time in seconds,
+with an additional \"error\" field when the part failed.
+";
+
+const DEFAULT_RUNS: usize = 10;
+
+#[derive(Debug)]
+struct Options {
+    day: Option<u8>,
+    part: Option<Part>,
+    all: bool,
+    input_path: Option<String>,
+    bench: bool,
+    runs: usize,
+    format: Format,
+    check: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            day: None,
+            part: None,
+            all: false,
+            input_path: None,
+            bench: false,
+            runs: DEFAULT_RUNS,
+            format: Format::Text,
+            check: false,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Format {
+    Text,
+    Csv,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "csv" => Ok(Self::Csv),
+            "json" => Ok(Self::Json),
+            _ => Err(format!(
+                "invalid format {:?}, expected text, csv or json",
+                s
+            )),
+        }
+    }
+}
+
+fn main() {
+    let options = parse_args(env::args().skip(1)).unwrap_or_else(|err| {
+        eprintln!("error: {}\n\n{}", err, USAGE);
+        process::exit(2);
+    });
+
+    let parts = match options.part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+
+    if options.check {
+        let solutions = match options.day {
+            Some(day) => vec![get_solution(day)],
+            None => days::all(),
+        };
+
+        check(&solutions, &parts);
+
+        return;
+    }
+
+    let jobs: Vec<(&dyn DynSolution, String)> = if options.all {
+        days::all()
+            .into_iter()
+            .map(|solution| (solution, load(&InputSource::Bundled(solution.day()))))
+            .collect()
+    } else {
+        let day = options.day.expect("parse_args ensures a day is given");
+        let solution = get_solution(day);
+        let input = load(&InputSource::from_arg(options.input_path.as_deref(), day));
+
+        vec![(solution, input)]
+    };
+
+    let mut failed = false;
+
+    if options.bench {
+        let mut measures = Vec::new();
+
+        for (solution, input) in &jobs {
+            match solution.bench(input, options.runs) {
+                Ok(day_measures) => measures.extend(day_measures),
+                Err(err) => {
+                    eprintln!("error: {}", err);
+                    failed = true;
+                }
+            }
+        }
+
+        measures.retain(|measure| match measure.task {
+            Task::Parse => true,
+            Task::Solve { part, .. } => parts.contains(&part),
+        });
+
+        let result = match options.format {
+            Format::Text => bench::write_text(io::stdout(), &measures),
+            Format::Csv => bench::write_csv(io::stdout(), &measures),
+            Format::Json => bench::write_json(io::stdout(), &measures),
+        };
+
+        if let Err(err) = result {
+            eprintln!("error: could not write the report: {}", err);
+            failed = true;
+        }
+    } else {
+        for (solution, input) in jobs {
+            if !run(solution, &input, &parts, options.format) {
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn get_solution(day: u8) -> &'static dyn DynSolution {
+    days::get(day).unwrap_or_else(|| {
+        eprintln!("error: day {} is not solved", day);
+        process::exit(1);
+    })
+}
+
+fn check(solutions: &[&dyn DynSolution], parts: &[Part]) {
+    let mut failures = 0;
+
+    for solution in solutions {
+        match answers::check(*solution, parts) {
+            Ok(checks) => {
+                for check in checks {
+                    if check.outcome.is_failure() {
+                        failures += 1;
+                    }
+
+                    println!("{}", check);
+                }
+            }
+            Err(err) => {
+                failures += 1;
+                println!("Day {}: invalid answers, {}", solution.day(), err);
+            }
+        }
+    }
+
+    if failures > 0 {
+        eprintln!("{} check(s) failed", failures);
+        process::exit(1);
+    }
+}
+
+fn load(source: &InputSource) -> String {
+    source.load().unwrap_or_else(|err| {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    })
+}
+
+/// Prints the answers of `parts`, returns whether all of them succeeded.
+fn run(solution: &dyn DynSolution, input: &str, parts: &[Part], format: Format) -> bool {
+    // Parsing errors are reported along with the answers below.
+    for warning in solution.warnings(input).unwrap_or_default() {
+        eprintln!("warning: {}", warning);
+    }
+
+    let runs = match solution.run(input, parts) {
+        Ok(runs) => runs,
+        Err(err) => {
+            eprintln!("error: {}", err);
+
+            if format == Format::Json {
+                for part in parts {
+                    println!(
+                        "{{\"day\":{},\"part\":{},\"answer\":null,\"elapsed\":null,\"error\":{}}}",
+                        solution.day(),
+                        part,
+                        json::string(&err.to_string())
+                    );
+                }
+            }
+
+            return false;
+        }
+    };
+
+    let mut succeeded = true;
+
+    for run in runs {
+        if let Err(err) = &run.answer {
+            eprintln!("error: {}", err);
+            succeeded = false;
+        }
+
+        match format {
+            Format::Json => println!("{}", json_record(solution.day(), &run)),
+            _ => match run.answer {
+                Ok(Answer::Text(text)) => {
+                    println!("Day {}, part {}:\n{}", solution.day(), run.part, text)
+                }
+                Ok(answer) => println!("Day {}, part {}: {}", solution.day(), run.part, answer),
+                Err(_) => (),
+            },
+        }
+    }
+
+    succeeded
+}
+
+fn json_record(day: u8, run: &PartRun) -> String {
+    let elapsed = run.elapsed.as_secs_f64();
+
+    match &run.answer {
+        Ok(answer) => format!(
+            "{{\"day\":{},\"part\":{},\"answer\":{},\"elapsed\":{}}}",
+            day,
+            run.part,
+            json::answer(answer),
+            elapsed
+        ),
+        Err(err) => format!(
+            "{{\"day\":{},\"part\":{},\"answer\":null,\"elapsed\":{},\"error\":{}}}",
+            day,
+            run.part,
+            elapsed,
+            json::string(&err.to_string())
+        ),
+    }
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                print!("{}", USAGE);
+                process::exit(0);
+            }
+            "-a" | "--all" => options.all = true,
+            "-d" | "--day" => {
+                let value = args.next().ok_or("--day expects a value")?;
+                let day = value
+                    .parse()
+                    .map_err(|_| format!("invalid day {:?}", value))?;
+
+                options.day = Some(day);
+            }
+            "-p" | "--part" => {
+                let value = args.next().ok_or("--part expects a value")?;
+                let part = value
+                    .parse()
+                    .map_err(|_| format!("invalid part {:?}, expected 1 or 2", value))?;
+
+                options.part = Some(part);
+            }
+            "-b" | "--bench" => options.bench = true,
+            "-c" | "--check" => options.check = true,
+            "-n" | "--runs" => {
+                let value = args.next().ok_or("--runs expects a value")?;
+                let runs = value
+                    .parse()
+                    .ok()
+                    .filter(|runs| *runs > 0)
+                    .ok_or_else(|| format!("invalid number of runs {:?}", value))?;
+
+                options.runs = runs;
+            }
+            "-f" | "--format" => {
+                let value = args.next().ok_or("--format expects a value")?;
+
+                options.format = value.parse()?;
+            }
+            _ if arg.starts_with('-') && arg != "-" => {
+                return Err(format!("unknown option {:?}", arg))
+            }
+            _ if options.input_path.is_none() => options.input_path = Some(arg),
+            _ => return Err(format!("unexpected argument {:?}", arg)),
+        }
+    }
+
+    if options.format == Format::Csv && !options.bench {
+        return Err("the csv format is only available with --bench".into());
+    }
+
+    if options.check {
+        return match (options.bench, options.all, &options.input_path) {
+            (true, _, _) => Err("--check and --bench are mutually exclusive".into()),
+            (_, true, _) => Err("--check runs every day by default, --all is not needed".into()),
+            (_, _, Some(_)) => Err("--check only runs the bundled inputs".into()),
+            _ => Ok(options),
+        };
+    }
+
+    match (options.all, options.day, &options.input_path) {
+        (true, Some(_), _) => Err("--all and --day are mutually exclusive".into()),
+        (true, None, Some(_)) => Err("an input cannot be given with --all".into()),
+        (false, None, _) => Err("either --day or --all is required".into()),
+        _ => Ok(options),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc2021::error::Error;
+    use std::time::Duration;
+
+    fn parse(args: &[&str]) -> Result<Options, String> {
+        parse_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn parse_day_and_part() {
+        let options = parse(&["--day", "4", "-p", "2", "input.txt"]).unwrap();
+
+        assert_eq!(options.day, Some(4));
+        assert_eq!(options.part, Some(Part::Two));
+        assert_eq!(options.input_path.as_deref(), Some("input.txt"));
+        assert!(!options.all);
+
+        let options = parse(&["--day", "4", "-"]).unwrap();
+
+        assert_eq!(options.input_path.as_deref(), Some("-"));
+    }
+
+    #[test]
+    fn parse_check() {
+        let options = parse(&["--check"]).unwrap();
+
+        assert!(options.check);
+        assert_eq!(options.day, None);
+
+        let options = parse(&["-c", "-d", "5", "-p", "1"]).unwrap();
+
+        assert_eq!(options.day, Some(5));
+        assert_eq!(options.part, Some(Part::One));
+    }
+
+    #[test]
+    fn parse_bench() {
+        let options = parse(&["--all", "--bench", "-n", "100", "--format", "csv"]).unwrap();
+
+        assert!(options.bench);
+        assert_eq!(options.runs, 100);
+        assert_eq!(options.format, Format::Csv);
+
+        let options = parse(&["--all", "--bench"]).unwrap();
+
+        assert_eq!(options.runs, DEFAULT_RUNS);
+        assert_eq!(options.format, Format::Text);
+    }
+
+    #[test]
+    fn parse_format() {
+        let options = parse(&["--day", "13", "--format", "json"]).unwrap();
+
+        assert_eq!(options.format, Format::Json);
+
+        let options = parse(&["--all", "-b", "-f", "json"]).unwrap();
+
+        assert_eq!(options.format, Format::Json);
+        assert!(parse(&["--day", "13", "--format", "csv"]).is_err());
+    }
+
+    #[test]
+    fn json_records() {
+        let run = PartRun {
+            part: Part::Two,
+            answer: Ok(Answer::Text("# #\n #".into())),
+            elapsed: Duration::from_millis(1500),
+        };
+
+        assert_eq!(
+            json_record(13, &run),
+            r##"{"day":13,"part":2,"answer":"# #\n #","elapsed":1.5}"##
+        );
+
+        let run = PartRun {
+            part: Part::One,
+            answer: Err(Error::new("no fold instruction").in_day(13)),
+            elapsed: Duration::ZERO,
+        };
+
+        assert_eq!(
+            json_record(13, &run),
+            r#"{"day":13,"part":1,"answer":null,"elapsed":0,"error":"day 13: no fold instruction"}"#
+        );
+    }
+
+    #[test]
+    fn parse_invalid_args() {
+        assert!(parse(&[]).is_err());
+        assert!(parse(&["--day"]).is_err());
+        assert!(parse(&["--day", "four"]).is_err());
+        assert!(parse(&["--day", "4", "--part", "3"]).is_err());
+        assert!(parse(&["--all", "--day", "4"]).is_err());
+        assert!(parse(&["--all", "input.txt"]).is_err());
+        assert!(parse(&["--day", "4", "a.txt", "b.txt"]).is_err());
+        assert!(parse(&["--verbose"]).is_err());
+        assert!(parse(&["--all", "--bench", "--runs", "0"]).is_err());
+        assert!(parse(&["--all", "--format", "xml"]).is_err());
+        assert!(parse(&["--check", "--bench"]).is_err());
+        assert!(parse(&["--check", "--day", "4", "input.txt"]).is_err());
+    }
+}
@@ -0,0 +1,158 @@
+use aoc2021::days::day4::{self, bingo::WinRule, odds};
+use aoc2021::input::InputSource;
+use std::{env, process};
+
+const USAGE: &str = "\
+Usage:
+    bingo-odds [--games <N>] [--seed <SEED>] [--rule <RULE>] [INPUT]
+
+Plays the boards of a day 4 input against random permutations of its draw,
+and reports the odds of each board to win first and last.
+
+Options:
+    -g, --games <N>      Number of games to play [default: 10000]
+    -s, --seed <SEED>    Seed of the random draws [default: 2021]
+    -r, --rule <RULE>    How boards win: rows, columns, lines, diagonals,
+                         corners or blackout, several rules being joined
+                         with + as in lines+diagonals [default: lines]
+    -h, --help           Print this message
+
+INPUT is the path of the puzzle input, or - to read it from stdin. The input.txt
+bundled with day 4 is used when omitted.
+";
+
+const DEFAULT_GAMES: usize = 10000;
+const DEFAULT_SEED: u64 = 2021;
+
+#[derive(Debug, PartialEq, Eq)]
+struct Options {
+    games: usize,
+    seed: u64,
+    rule: WinRule,
+    input_path: Option<String>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            games: DEFAULT_GAMES,
+            seed: DEFAULT_SEED,
+            rule: WinRule::lines(),
+            input_path: None,
+        }
+    }
+}
+
+fn main() {
+    let options = parse_args(env::args().skip(1)).unwrap_or_else(|err| {
+        eprintln!("error: {}\n\n{}", err, USAGE);
+        process::exit(2);
+    });
+
+    let input = InputSource::from_arg(options.input_path.as_deref(), 4)
+        .load()
+        .unwrap_or_else(|err| {
+            eprintln!("error: {}", err);
+            process::exit(1);
+        });
+
+    let (draw, boards) = day4::parse_input(&input).unwrap_or_else(|err| {
+        eprintln!("error: {}", err.in_day(4));
+        process::exit(1);
+    });
+
+    let odds = odds::estimate(&draw, &boards, &options.rule, options.games, options.seed);
+
+    println!(
+        "{} games, seed {}:\n\t{:>5}  {:>7}  {:>7}  {:>7}  {:>14}",
+        options.games, options.seed, "board", "first", "last", "wins", "expected draws"
+    );
+
+    for board in odds {
+        println!(
+            "\t{:>5}  {:>6.2}%  {:>6.2}%  {:>6.2}%  {:>14}",
+            board.board,
+            board.first * 100.0,
+            board.last * 100.0,
+            board.wins * 100.0,
+            board
+                .expected_draws
+                .map_or("-".to_string(), |draws| format!("{:.2}", draws)),
+        );
+    }
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                print!("{}", USAGE);
+                process::exit(0);
+            }
+            "-g" | "--games" => {
+                let value = args.next().ok_or("--games expects a value")?;
+
+                options.games = value
+                    .parse()
+                    .ok()
+                    .filter(|games| *games > 0)
+                    .ok_or_else(|| format!("invalid number of games {:?}", value))?;
+            }
+            "-s" | "--seed" => {
+                let value = args.next().ok_or("--seed expects a value")?;
+
+                options.seed = value
+                    .parse()
+                    .map_err(|_| format!("invalid seed {:?}", value))?;
+            }
+            "-r" | "--rule" => {
+                let value = args.next().ok_or("--rule expects a value")?;
+
+                options.rule = value
+                    .parse()
+                    .map_err(|err: day4::bingo::ParseWinRuleError| err.to_string())?;
+            }
+            _ if arg.starts_with('-') && arg != "-" => {
+                return Err(format!("unknown option {:?}", arg))
+            }
+            _ if options.input_path.is_none() => options.input_path = Some(arg),
+            _ => return Err(format!("unexpected argument {:?}", arg)),
+        }
+    }
+
+    Ok(options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Options, String> {
+        parse_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn parse_options() {
+        assert_eq!(parse(&[]), Ok(Options::default()));
+        assert_eq!(
+            parse(&["-g", "50", "--seed", "7", "-r", "blackout", "input.txt"]),
+            Ok(Options {
+                games: 50,
+                seed: 7,
+                rule: WinRule::Blackout,
+                input_path: Some("input.txt".into()),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_invalid_args() {
+        assert!(parse(&["--games", "0"]).is_err());
+        assert!(parse(&["--seed", "-1"]).is_err());
+        assert!(parse(&["a.txt", "b.txt"]).is_err());
+        assert!(parse(&["--verbose"]).is_err());
+        assert!(parse(&["--rule", "stars"]).is_err());
+    }
+}
@@ -0,0 +1,200 @@
+use aoc2021::days::day5::{
+    self,
+    map::VentMap,
+    render::{self, Heatmap, Intensity, Scale},
+};
+use aoc2021::input::InputSource;
+use std::io::{self, Write};
+use std::str::FromStr;
+use std::{env, process};
+
+const USAGE: &str = "\
+Usage:
+    vents [--format <FORMAT>] [--enlarge <N> | --shrink <N>] [--log] [--axial] [INPUT]
+
+Draws how many hydrothermal vent lines of a day 5 input cover each point.
+
+Options:
+    -f, --format <FORMAT>
+                         ascii, pgm, ppm or svg [default: ascii]
+    -e, --enlarge <N>    Draw each point as a square of N pixels [default: 1]
+    -s, --shrink <N>     Draw the densest point of each square of N points as
+                         a single pixel
+    -l, --log            Scale the brightness of pixels logarithmically
+    -a, --axial          Only draw the horizontal and vertical lines
+    -h, --help           Print this message
+
+INPUT is the path of the puzzle input, or - to read it from stdin. The input.txt
+bundled with day 5 is used when omitted. Images are written to stdout.
+";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Format {
+    Ascii,
+    Pgm,
+    Ppm,
+    Svg,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ascii" => Ok(Self::Ascii),
+            "pgm" => Ok(Self::Pgm),
+            "ppm" => Ok(Self::Ppm),
+            "svg" => Ok(Self::Svg),
+            _ => Err(format!(
+                "invalid format {:?}, expected ascii, pgm, ppm or svg",
+                s
+            )),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Options {
+    format: Format,
+    heatmap: Heatmap,
+    axial: bool,
+    input_path: Option<String>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            format: Format::Ascii,
+            heatmap: Heatmap::default(),
+            axial: false,
+            input_path: None,
+        }
+    }
+}
+
+fn main() {
+    let options = parse_args(env::args().skip(1)).unwrap_or_else(|err| {
+        eprintln!("error: {}\n\n{}", err, USAGE);
+        process::exit(2);
+    });
+
+    let input = InputSource::from_arg(options.input_path.as_deref(), 5)
+        .load()
+        .unwrap_or_else(|err| {
+            eprintln!("error: {}", err);
+            process::exit(1);
+        });
+
+    let segments = day5::parse(&input).unwrap_or_else(|err| {
+        eprintln!("error: {}", err.in_day(5));
+        process::exit(1);
+    });
+
+    let map = if options.axial {
+        VentMap::filtered(&segments, day5::Segment::is_axial)
+    } else {
+        VentMap::new(&segments)
+    };
+
+    let stdout = io::stdout().lock();
+    let result = match options.format {
+        Format::Ascii => render::ascii(&map).and_then(|diagram| {
+            io::BufWriter::new(stdout).write_all(diagram.as_bytes())?;
+            Ok(())
+        }),
+        Format::Pgm => options.heatmap.write_pgm(io::BufWriter::new(stdout), &map),
+        Format::Ppm => options.heatmap.write_ppm(io::BufWriter::new(stdout), &map),
+        Format::Svg => options.heatmap.write_svg(io::BufWriter::new(stdout), &map),
+    };
+
+    if let Err(err) = result {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    }
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                print!("{}", USAGE);
+                process::exit(0);
+            }
+            "-f" | "--format" => {
+                let value = args.next().ok_or("--format expects a value")?;
+
+                options.format = value.parse()?;
+            }
+            "-e" | "--enlarge" => {
+                options.heatmap.scale = Scale::Enlarge(parse_size(&arg, args.next())?)
+            }
+            "-s" | "--shrink" => {
+                options.heatmap.scale = Scale::Shrink(parse_size(&arg, args.next())?)
+            }
+            "-l" | "--log" => options.heatmap.intensity = Intensity::Logarithmic,
+            "-a" | "--axial" => options.axial = true,
+            _ if arg.starts_with('-') && arg != "-" => {
+                return Err(format!("unknown option {:?}", arg))
+            }
+            _ if options.input_path.is_none() => options.input_path = Some(arg),
+            _ => return Err(format!("unexpected argument {:?}", arg)),
+        }
+    }
+
+    if options.format == Format::Ascii && options.heatmap != Heatmap::default() {
+        return Err("scaling options only apply to images".into());
+    }
+
+    Ok(options)
+}
+
+fn parse_size(option: &str, value: Option<String>) -> Result<usize, String> {
+    let value = value.ok_or_else(|| format!("{} expects a value", option))?;
+
+    value
+        .parse()
+        .ok()
+        .filter(|size| *size > 0)
+        .ok_or_else(|| format!("invalid size {:?}", value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Options, String> {
+        parse_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn parse_options() {
+        assert_eq!(parse(&[]), Ok(Options::default()));
+
+        let options = parse(&["-f", "ppm", "--shrink", "10", "--log", "-a", "-"]).unwrap();
+
+        assert_eq!(options.format, Format::Ppm);
+        assert_eq!(
+            options.heatmap,
+            Heatmap {
+                scale: Scale::Shrink(10),
+                intensity: Intensity::Logarithmic,
+            }
+        );
+        assert!(options.axial);
+        assert_eq!(options.input_path.as_deref(), Some("-"));
+
+        let options = parse(&["-f", "svg", "-e", "4"]).unwrap();
+
+        assert_eq!(options.heatmap.scale, Scale::Enlarge(4));
+    }
+
+    #[test]
+    fn parse_invalid_args() {
+        assert!(parse(&["--format", "png"]).is_err());
+        assert!(parse(&["-f", "pgm", "--enlarge", "0"]).is_err());
+        assert!(parse(&["--shrink", "2"]).is_err());
+        assert!(parse(&["a.txt", "b.txt"]).is_err());
+    }
+}
@@ -0,0 +1,78 @@
+use crate::error;
+use crate::solution::{Answer, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    const INPUT: &'static str = include_str!("input.txt");
+    const ANSWERS: &'static str = include_str!("answers.txt");
+
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> error::Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(measures: &Self::Input) -> error::Result<Answer> {
+        Ok(part1(measures).into())
+    }
+
+    fn part2(measures: &Self::Input) -> error::Result<Answer> {
+        Ok(part2(measures).into())
+    }
+}
+
+pub fn parse(measures_input: &str) -> error::Result<Vec<i32>> {
+    error::parse_lines(measures_input)
+}
+
+pub fn part1(measures: &[i32]) -> u32 {
+    measures
+        .windows(2)
+        .fold(0, |increase_count, window| match window {
+            [a, b] if a < b => increase_count + 1,
+            _ => increase_count,
+        })
+}
+
+pub fn part2(measures: &[i32]) -> u32 {
+    measures
+        .windows(3)
+        .map(|window| window.iter().sum())
+        .collect::<Vec<i32>>()
+        .as_slice()
+        .windows(2)
+        .fold(0, |increase_count, window| match window {
+            [a, b] if a < b => increase_count + 1,
+            _ => increase_count,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_MEASURES: &str = "\
+199
+200
+208
+210
+200
+207
+240
+269
+260
+263
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(7, part1(&parse(EXAMPLE_MEASURES).unwrap()));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(5, part2(&parse(EXAMPLE_MEASURES).unwrap()));
+    }
+}
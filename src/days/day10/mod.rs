@@ -1,20 +1,56 @@
+use crate::error::{self, Error};
+use crate::solution::{Answer, Solution};
 use std::fmt::{Display, Formatter};
 
-fn main() {
-    let input = include_str!("input.txt");
+pub struct Day10;
 
-    println!("{}", part1(input));
-    println!("{}", part2(input));
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const INPUT: &'static str = include_str!("input.txt");
+    const ANSWERS: &'static str = include_str!("answers.txt");
+
+    type Input = Vec<Vec<Symbol>>;
+
+    fn parse(input: &str) -> error::Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(lines: &Self::Input) -> error::Result<Answer> {
+        Ok(part1(lines).into())
+    }
+
+    fn part2(lines: &Self::Input) -> error::Result<Answer> {
+        part2(lines).map(Answer::from)
+    }
 }
 
-fn part1(input: &str) -> u64 {
+pub fn parse(input: &str) -> error::Result<Vec<Vec<Symbol>>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(line_index, line)| {
+            line.chars()
+                .enumerate()
+                .map(|(column, c)| {
+                    Symbol::try_from(c).map_err(|_| {
+                        Error::new(format!("unexpected symbol {:?}", c))
+                            .at_line(line_index + 1)
+                            .at_column(column + 1)
+                    })
+                })
+                .collect()
+        })
+        .collect()
+}
+
+pub fn part1(lines: &[Vec<Symbol>]) -> u64 {
     let mut stack = Vec::new();
     let mut illegal_score = 0;
 
-    for line in input.lines() {
+    for line in lines {
         stack.clear();
 
-        for symbol in line.chars().map(|c| Symbol::try_from(c).unwrap()) {
+        for symbol in line {
             if symbol.is_opening() {
                 stack.push(symbol);
                 continue;
@@ -28,7 +64,7 @@ fn part1(input: &str) -> u64 {
 
             let opening = opening.unwrap();
 
-            if !opening.is_closed_by(&symbol) {
+            if !opening.is_closed_by(symbol) {
                 illegal_score += symbol.illegal_score();
             }
         }
@@ -37,14 +73,14 @@ fn part1(input: &str) -> u64 {
     illegal_score
 }
 
-fn part2(input: &str) -> u64 {
+pub fn part2(lines: &[Vec<Symbol>]) -> error::Result<u64> {
     let mut stack;
     let mut line_scores = Vec::new();
 
-    'line_loop: for line in input.lines() {
+    'line_loop: for line in lines {
         stack = Vec::new();
 
-        'symbol_loop: for symbol in line.chars().map(|c| Symbol::try_from(c).unwrap()) {
+        'symbol_loop: for symbol in line {
             if symbol.is_opening() {
                 stack.push(symbol);
                 continue 'symbol_loop; // go to next symbol in the line
@@ -60,7 +96,7 @@ fn part2(input: &str) -> u64 {
             }
 
             let opening = opening.unwrap();
-            if !opening.is_closed_by(&symbol) {
+            if !opening.is_closed_by(symbol) {
                 // corrupted: this closing symbol does not match its opening
                 continue 'line_loop; // go to next line in input
             }
@@ -81,14 +117,17 @@ fn part2(input: &str) -> u64 {
 
     line_scores.sort_unstable();
 
-    line_scores[line_scores.len() / 2]
+    line_scores
+        .get(line_scores.len() / 2)
+        .copied()
+        .ok_or_else(|| Error::new("no incomplete line"))
 }
 
 #[derive(Debug)]
-struct ParseSymbolError;
+pub struct ParseSymbolError;
 
 #[derive(Debug)]
-enum Symbol {
+pub enum Symbol {
     Opening(char),
     Closing(char),
 }
@@ -185,11 +224,19 @@ mod tests {
 
     #[test]
     fn part1_example() {
-        assert_eq!(part1(EXAMPLE), 26397);
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), 26397);
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(part2(EXAMPLE), 288957);
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), Ok(288957));
+    }
+
+    #[test]
+    fn invalid_input() {
+        let err = parse("[()]\n[(x)]\n").unwrap_err();
+        assert_eq!((err.line(), err.column()), (Some(2), Some(3)));
+
+        assert!(part2(&parse("[()]\n").unwrap()).is_err());
     }
 }
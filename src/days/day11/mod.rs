@@ -0,0 +1,128 @@
+use crate::error::{self, Error};
+use crate::grid::Grid;
+use crate::solution::{Answer, Solution};
+use std::collections::HashSet;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const INPUT: &'static str = include_str!("input.txt");
+    const ANSWERS: &'static str = include_str!("answers.txt");
+
+    type Input = Grid<u8>;
+
+    fn parse(input: &str) -> error::Result<Self::Input> {
+        parse_grid(input)
+    }
+
+    fn part1(grid: &Self::Input) -> error::Result<Answer> {
+        Ok(part1(grid).into())
+    }
+
+    fn part2(grid: &Self::Input) -> error::Result<Answer> {
+        Ok(part2(grid)?.into())
+    }
+}
+
+/// Number of flashes during the first 100 steps.
+pub fn part1(grid: &Grid<u8>) -> usize {
+    let mut grid = grid.clone();
+
+    (0..100).map(|_| step(&mut grid)).sum()
+}
+
+/// First step during which every octopus flashes. Fails once the grid comes
+/// back to a state it already went through without ever synchronizing.
+pub fn part2(grid: &Grid<u8>) -> error::Result<usize> {
+    let mut grid = grid.clone();
+    let mut seen = HashSet::new();
+
+    for step_number in 1.. {
+        if !seen.insert(grid.cells().to_vec()) {
+            return Err(Error::new(format!(
+                "the octopuses never flash all at once, the grid after step {} repeats an earlier one",
+                step_number - 1
+            )));
+        }
+
+        if step(&mut grid) == grid.len() {
+            return Ok(step_number);
+        }
+    }
+
+    unreachable!("steps are unbounded");
+}
+
+/// Runs a step, returning how many octopuses flashed.
+fn step(grid: &mut Grid<u8>) -> usize {
+    let mut flashing = Vec::new();
+    let mut flashes = 0;
+
+    for (index, energy) in grid.cells_mut().iter_mut().enumerate() {
+        *energy += 1;
+
+        if *energy > 9 {
+            flashing.push(index);
+        }
+    }
+
+    while let Some(flashing_i) = flashing.pop() {
+        grid[flashing_i] = 0;
+        flashes += 1;
+
+        let adj_indices: Vec<_> = grid.neighbours8(flashing_i).collect();
+
+        for adj_i in adj_indices {
+            if grid[adj_i] == 0 || grid[adj_i] > 9 {
+                continue;
+            }
+
+            grid[adj_i] += 1;
+
+            if grid[adj_i] > 9 {
+                flashing.push(adj_i);
+            }
+        }
+    }
+
+    flashes
+}
+
+pub fn parse_grid(input: &str) -> error::Result<Grid<u8>> {
+    Ok(input.trim().parse()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526
+";
+
+    #[test]
+    fn example() {
+        let grid = parse_grid(EXAMPLE).unwrap();
+
+        assert_eq!(part1(&grid), 1656);
+        assert_eq!(part2(&grid), Ok(195));
+    }
+
+    #[test]
+    fn never_synchronized() {
+        let grid = parse_grid("09\n").unwrap();
+
+        assert_eq!(part1(&grid), 23);
+        assert!(part2(&grid).is_err());
+    }
+}
@@ -0,0 +1,294 @@
+use crate::error::{self, Error};
+use crate::solution::{Answer, Solution};
+use std::{
+    collections::HashSet,
+    fmt::{Debug, Display, Formatter},
+    num::ParseIntError,
+    str::FromStr,
+};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const INPUT: &'static str = include_str!("input.txt");
+    const ANSWERS: &'static str = include_str!("answers.txt");
+
+    type Input = (HashSet<Dot>, Vec<Fold>);
+
+    fn parse(input: &str) -> error::Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1((dots, folds): &Self::Input) -> error::Result<Answer> {
+        part1(dots, folds).map(Answer::from)
+    }
+
+    fn part2((dots, folds): &Self::Input) -> error::Result<Answer> {
+        part2(dots, folds).map(Answer::Text)
+    }
+}
+
+pub fn parse(input: &str) -> error::Result<(HashSet<Dot>, Vec<Fold>)> {
+    let mut lines = input.lines().enumerate();
+
+    let dots: HashSet<Dot> = lines
+        .by_ref()
+        .take_while(|(_, line)| !line.is_empty())
+        .map(|(index, line)| parse_line(index, line))
+        .collect::<error::Result<_>>()?;
+
+    let folds: Vec<Fold> = lines
+        .map(|(index, line)| parse_line(index, line))
+        .collect::<error::Result<_>>()?;
+
+    Ok((dots, folds))
+}
+
+fn parse_line<T>(index: usize, line: &str) -> error::Result<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    line.parse()
+        .map_err(|err| Error::new(format!("{}: {:?}", err, line)).at_line(index + 1))
+}
+
+pub fn part1(dots: &HashSet<Dot>, folds: &[Fold]) -> error::Result<usize> {
+    let first_fold = folds
+        .first()
+        .ok_or_else(|| Error::new("no fold instruction"))?;
+
+    let dots = fold(dots.clone(), first_fold);
+
+    Ok(dots.len())
+}
+
+/// Folds the whole sheet and renders the remaining dots, one line per row.
+pub fn part2(dots: &HashSet<Dot>, folds: &[Fold]) -> error::Result<String> {
+    let mut dots = dots.clone();
+
+    for f in folds {
+        dots = fold(dots, f);
+    }
+
+    // Rotate the result to improve display :)
+    let dots: HashSet<_> = dots
+        .into_iter()
+        .map(|dot| Dot { x: dot.y, y: dot.x })
+        .collect();
+
+    if dots.is_empty() {
+        return Err(Error::new("no dots to display"));
+    }
+
+    let min_x = dots.iter().map(|dot| dot.x).min().unwrap_or_default();
+    let min_y = dots.iter().map(|dot| dot.y).min().unwrap_or_default();
+    let max_x = dots.iter().map(|dot| dot.x).max().unwrap_or_default();
+    let max_y = dots.iter().map(|dot| dot.y).max().unwrap_or_default();
+
+    let rows: Vec<String> = (min_x..=max_x)
+        .map(|x| {
+            (min_y..=max_y)
+                .map(|y| {
+                    if dots.contains(&Dot { x, y }) {
+                        "#"
+                    } else {
+                        " "
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+                .trim_end()
+                .to_string()
+        })
+        .collect();
+
+    Ok(rows.join("\n"))
+}
+
+fn fold(dots: HashSet<Dot>, fold: &Fold) -> HashSet<Dot> {
+    dots.into_iter().map(|dot| dot.fold(fold)).collect()
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Dot {
+    x: i64,
+    y: i64,
+}
+
+impl Dot {
+    fn fold(&self, fold: &Fold) -> Self {
+        match fold {
+            Fold::Up(threshold) => Self {
+                x: self.x,
+                y: if self.y > *threshold {
+                    *threshold - (self.y - *threshold)
+                } else {
+                    self.y
+                },
+            },
+            Fold::Left(threshold) => Self {
+                x: if self.x > *threshold {
+                    *threshold - (self.x - *threshold)
+                } else {
+                    self.x
+                },
+                y: self.y,
+            },
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ParseDotError {
+    InvalidFormat,
+    InvalidInt(ParseIntError),
+}
+
+impl Display for ParseDotError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Self::InvalidFormat => write!(f, "expected a dot like `x,y`"),
+            Self::InvalidInt(err) => write!(f, "invalid coordinate ({})", err),
+        }
+    }
+}
+
+impl From<ParseIntError> for ParseDotError {
+    fn from(e: ParseIntError) -> Self {
+        Self::InvalidInt(e)
+    }
+}
+
+impl FromStr for Dot {
+    type Err = ParseDotError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split(',');
+
+        let x = parts.next().ok_or(Self::Err::InvalidFormat)?.parse()?;
+        let y = parts.next().ok_or(Self::Err::InvalidFormat)?.parse()?;
+
+        if parts.next().is_some() {
+            Err(Self::Err::InvalidFormat)
+        } else {
+            Ok(Self { x, y })
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Fold {
+    Up(i64),
+    Left(i64),
+}
+
+#[derive(Debug)]
+pub enum ParseFoldError {
+    InvalidFormat,
+    InvalidInt(ParseIntError),
+}
+
+impl Display for ParseFoldError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Self::InvalidFormat => write!(f, "expected a fold like `fold along x=5`"),
+            Self::InvalidInt(err) => write!(f, "invalid fold position ({})", err),
+        }
+    }
+}
+
+impl From<ParseIntError> for ParseFoldError {
+    fn from(e: ParseIntError) -> Self {
+        Self::InvalidInt(e)
+    }
+}
+
+impl FromStr for Fold {
+    type Err = ParseFoldError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if !s.starts_with("fold along ") {
+            return Err(Self::Err::InvalidFormat);
+        }
+
+        let mut parts = s[11..].split('=');
+
+        let axis = parts.next().ok_or(Self::Err::InvalidFormat)?;
+        let threshold = parts.next().ok_or(Self::Err::InvalidFormat)?.parse()?;
+
+        if parts.next().is_some() {
+            return Err(Self::Err::InvalidFormat);
+        }
+
+        match axis {
+            "x" => Ok(Fold::Left(threshold)),
+            "y" => Ok(Fold::Up(threshold)),
+            _ => Err(Self::Err::InvalidFormat),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
+
+fold along y=7
+fold along x=5
+";
+
+    #[test]
+    fn part1_example() {
+        let (dots, folds) = parse(EXAMPLE).unwrap();
+
+        assert_eq!(part1(&dots, &folds), Ok(17));
+    }
+
+    #[test]
+    fn part2_example() {
+        let (dots, folds) = parse(EXAMPLE).unwrap();
+
+        assert_eq!(
+            part2(&dots, &folds).unwrap(),
+            "\
+# # # # #
+#       #
+#       #
+#       #
+# # # # #"
+        );
+    }
+
+    #[test]
+    fn invalid_input() {
+        let err = parse("6,10\n0,x\n\nfold along y=7\n").unwrap_err();
+        assert_eq!(err.line(), Some(2));
+
+        let err = parse("6,10\n\nfold along z=7\n").unwrap_err();
+        assert_eq!(err.line(), Some(3));
+
+        let (dots, folds) = parse("6,10\n").unwrap();
+        assert!(part1(&dots, &folds).is_err());
+    }
+}
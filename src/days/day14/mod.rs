@@ -0,0 +1,291 @@
+use crate::error::{self, Error};
+use crate::solution::{Answer, Solution};
+use std::collections::HashMap;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const INPUT: &'static str = include_str!("input.txt");
+    const ANSWERS: &'static str = include_str!("answers.txt");
+
+    type Input = (String, Rules);
+
+    fn parse(input: &str) -> error::Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1((template, rules): &Self::Input) -> error::Result<Answer> {
+        Ok(part1(template, rules).into())
+    }
+
+    fn part2((template, rules): &Self::Input) -> error::Result<Answer> {
+        Ok(part2(template, rules).into())
+    }
+}
+
+pub type Rules = HashMap<(char, char), char>;
+
+pub fn parse(input: &str) -> error::Result<(String, Rules)> {
+    let mut lines = input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line));
+
+    let template: String = match lines.next() {
+        Some((_, template)) if !template.is_empty() => template.into(),
+        _ => return Err(Error::new("missing polymer template").at_line(1)),
+    };
+
+    match lines.next() {
+        Some((_, "")) => (),
+        _ => return Err(Error::new("expected an empty line after the template").at_line(2)),
+    }
+
+    let rules = lines
+        .map(|(line_number, line)| {
+            parse_rule(line).ok_or_else(|| {
+                Error::new(format!("expected a rule like `AB -> C`, got {:?}", line))
+                    .at_line(line_number)
+            })
+        })
+        .collect::<error::Result<_>>()?;
+
+    Ok((template, rules))
+}
+
+fn parse_rule(line: &str) -> Option<((char, char), char)> {
+    let (pattern, insertion) = line.split_once(" -> ")?;
+
+    let mut chars = pattern.chars();
+    let c1 = chars.next()?;
+    let c2 = chars.next()?;
+
+    if chars.next().is_some() {
+        return None;
+    }
+
+    let mut chars = insertion.chars();
+    let insertion = chars.next()?;
+
+    if chars.next().is_some() {
+        return None;
+    }
+
+    Some(((c1, c2), insertion))
+}
+
+pub fn part1(template: &str, rules: &Rules) -> u64 {
+    grow(template, rules, 10)
+}
+
+pub fn part2(template: &str, rules: &Rules) -> u64 {
+    grow(template, rules, 40)
+}
+
+/// Difference between the quantities of the most and least common elements
+/// after `steps` insertion steps.
+fn grow(template: &str, rules: &Rules, steps: usize) -> u64 {
+    let mut polymer = Polymer::new(template);
+
+    for _step in 1..=steps {
+        polymer = polymer.apply(rules);
+    }
+
+    spread(&polymer.occurrences())
+}
+
+fn spread(occurrences: &HashMap<char, u64>) -> u64 {
+    let (min, max) = occurrences
+        .iter()
+        .fold((u64::MAX, u64::MIN), |(min, max), (_c, current)| {
+            (
+                if *current < min { *current } else { min },
+                if *current > max { *current } else { max },
+            )
+        });
+
+    max.saturating_sub(min)
+}
+
+/// A polymer known only by how many times each pair of adjacent elements
+/// appears in it, so that its size does not matter.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Polymer {
+    pairs: HashMap<(char, char), u64>,
+    /// Insertions never happen after the last element, which is the only one
+    /// not starting a pair.
+    last: Option<char>,
+}
+
+impl Polymer {
+    pub fn new(template: &str) -> Self {
+        let chars: Vec<char> = template.chars().collect();
+        let mut pairs = HashMap::new();
+
+        for pair in chars.windows(2) {
+            *pairs.entry((pair[0], pair[1])).or_default() += 1;
+        }
+
+        Self {
+            pairs,
+            last: chars.last().copied(),
+        }
+    }
+
+    /// Inserts elements between every pair matching a rule, each matching
+    /// pair `AB` with insertion `C` becoming pairs `AC` and `CB`.
+    pub fn apply(&self, rules: &Rules) -> Self {
+        let mut pairs = HashMap::new();
+
+        for (&(c1, c2), &count) in &self.pairs {
+            match rules.get(&(c1, c2)) {
+                Some(&insertion) => {
+                    *pairs.entry((c1, insertion)).or_default() += count;
+                    *pairs.entry((insertion, c2)).or_default() += count;
+                }
+                None => *pairs.entry((c1, c2)).or_default() += count,
+            }
+        }
+
+        Self {
+            pairs,
+            last: self.last,
+        }
+    }
+
+    pub fn occurrences(&self) -> HashMap<char, u64> {
+        let mut occurrences: HashMap<char, u64> = HashMap::new();
+
+        for (&(c1, _c2), &count) in &self.pairs {
+            *occurrences.entry(c1).or_default() += count;
+        }
+
+        if let Some(last) = self.last {
+            *occurrences.entry(last).or_default() += 1;
+        }
+
+        occurrences
+    }
+
+    pub fn len(&self) -> u64 {
+        self.pairs.values().sum::<u64>() + u64::from(self.last.is_some())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.last.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Reference implementation, building the whole polymer.
+    fn apply(rules: &Rules, template: String) -> String {
+        template
+            .chars()
+            .collect::<Vec<_>>()
+            .as_slice()
+            .windows(2)
+            .fold(
+                template
+                    .chars()
+                    .next()
+                    .map(String::from)
+                    .unwrap_or_default(),
+                |acc, pattern| {
+                    let mut pattern_chars = pattern.iter();
+
+                    let c1 = pattern_chars.next().unwrap();
+                    let c2 = pattern_chars.next().unwrap();
+
+                    match rules.get(&(*c1, *c2)) {
+                        Some(insertion) => format!("{}{}{}", acc, insertion, c2),
+                        None => format!("{}{}", acc, c2),
+                    }
+                },
+            )
+    }
+
+    fn occurrences(template: &str) -> HashMap<char, u64> {
+        let mut occurrences: HashMap<char, u64> = HashMap::new();
+
+        for c in template.chars() {
+            *occurrences.entry(c).or_default() += 1;
+        }
+
+        occurrences
+    }
+
+    const EXAMPLE: &str = "\
+NNCB
+
+CH -> B
+HH -> N
+CB -> H
+NH -> C
+HB -> C
+HC -> B
+HN -> C
+NN -> C
+BH -> H
+NC -> B
+NB -> B
+BN -> B
+BB -> N
+BC -> B
+CC -> N
+CN -> C
+";
+
+    #[test]
+    fn part1_example() {
+        let (template, rules) = parse(EXAMPLE).unwrap();
+
+        assert_eq!(part1(&template, &rules), 1588);
+    }
+
+    #[test]
+    fn part2_example() {
+        let (template, rules) = parse(EXAMPLE).unwrap();
+
+        assert_eq!(part2(&template, &rules), 2188189693529);
+    }
+
+    #[test]
+    fn polymer_matches_string() {
+        let (template, rules) = parse(EXAMPLE).unwrap();
+        let mut polymer = Polymer::new(&template);
+        let mut string = template.clone();
+
+        for step in 1..=10 {
+            string = apply(&rules, string);
+            polymer = polymer.apply(&rules);
+
+            assert_eq!(polymer.len(), string.len() as u64, "step {}", step);
+            assert_eq!(polymer.occurrences(), occurrences(&string), "step {}", step);
+        }
+
+        assert_eq!(spread(&occurrences(&string)), part1(&template, &rules));
+    }
+
+    #[test]
+    fn single_element_polymer() {
+        let polymer = Polymer::new("N");
+
+        assert_eq!(polymer.len(), 1);
+        assert_eq!(polymer.apply(&Rules::new()).occurrences(), occurrences("N"));
+        assert!(Polymer::new("").is_empty());
+    }
+
+    #[test]
+    fn invalid_input() {
+        assert_eq!(parse("\n\nCH -> B\n").unwrap_err().line(), Some(1));
+        assert_eq!(parse("NNCB\nCH -> B\n").unwrap_err().line(), Some(2));
+        assert_eq!(
+            parse("NNCB\n\nCH -> B\nCHH -> B\n").unwrap_err().line(),
+            Some(4)
+        );
+    }
+}
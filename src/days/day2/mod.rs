@@ -1,37 +1,54 @@
-#[macro_use]
-extern crate lazy_static;
-
+use crate::error;
+use crate::solution::{Answer, Solution};
+use lazy_static::lazy_static;
 use regex::Regex;
+use std::fmt::{Display, Formatter};
 use std::num::ParseIntError;
 use std::str::FromStr;
 
-fn main() {
-    let input = include_str!("input.txt");
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    const INPUT: &'static str = include_str!("input.txt");
+    const ANSWERS: &'static str = include_str!("answers.txt");
+
+    type Input = Vec<SubmarineCommand>;
+
+    fn parse(input: &str) -> error::Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(course: &Self::Input) -> error::Result<Answer> {
+        Ok(part1(course).into())
+    }
+
+    fn part2(course: &Self::Input) -> error::Result<Answer> {
+        Ok(part2(course).into())
+    }
+}
 
-    println!("{}", part1(input));
-    println!("{}", part2(input));
+pub fn parse(input_commands: &str) -> error::Result<Vec<SubmarineCommand>> {
+    error::parse_lines(input_commands)
 }
 
-fn part1(input_commands: &str) -> i32 {
-    part(input_commands, Box::new(SubmarinePart1::default()))
+pub fn part1(course: &[SubmarineCommand]) -> i32 {
+    part(course, Box::new(SubmarinePart1::default()))
 }
 
-fn part2(input_commands: &str) -> i32 {
-    part(input_commands, Box::new(SubmarinePart2::default()))
+pub fn part2(course: &[SubmarineCommand]) -> i32 {
+    part(course, Box::new(SubmarinePart2::default()))
 }
 
-fn part(input_commands: &str, mut sub: Box<dyn Submarine>) -> i32 {
-    sub.follow_course(input_commands);
+fn part(course: &[SubmarineCommand], mut sub: Box<dyn Submarine>) -> i32 {
+    sub.follow_course(course);
 
     sub.hpos() * sub.depth()
 }
 
 trait Submarine {
-    fn follow_course(&mut self, course: &str) {
-        course
-            .lines()
-            .map(|line| line.parse().expect("Invalid command"))
-            .for_each(|command| self.command(&command));
+    fn follow_course(&mut self, course: &[SubmarineCommand]) {
+        course.iter().for_each(|command| self.command(command));
     }
 
     fn command(&mut self, command: &SubmarineCommand);
@@ -92,19 +109,29 @@ impl Submarine for SubmarinePart2 {
 }
 
 #[derive(Debug, PartialEq)]
-enum SubmarineCommand {
+pub enum SubmarineCommand {
     Forward(i32),
     Up(i32),
     Down(i32),
 }
 
 #[derive(Debug, PartialEq)]
-enum SubmarineCommandParseError {
+pub enum SubmarineCommandParseError {
     NoMatch,
     UnknownCommand,
     ParseIntError(ParseIntError),
 }
 
+impl Display for SubmarineCommandParseError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Self::NoMatch => write!(f, "expected a command followed by an amount"),
+            Self::UnknownCommand => write!(f, "unknown command, expected forward, up or down"),
+            Self::ParseIntError(err) => write!(f, "invalid amount ({})", err),
+        }
+    }
+}
+
 impl From<ParseIntError> for SubmarineCommandParseError {
     fn from(err: ParseIntError) -> Self {
         SubmarineCommandParseError::ParseIntError(err)
@@ -208,11 +235,11 @@ forward 2
 
     #[test]
     fn part1_example() {
-        assert_eq!(150, part1(EXAMPLE));
+        assert_eq!(150, part1(&parse(EXAMPLE).unwrap()));
     }
 
     #[test]
     fn part2_example() {
-        assert_eq!(900, part2(EXAMPLE));
+        assert_eq!(900, part2(&parse(EXAMPLE).unwrap()));
     }
 }
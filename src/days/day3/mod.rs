@@ -0,0 +1,224 @@
+use crate::error::{self, Error};
+use crate::solution::{Answer, Solution};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    const INPUT: &'static str = include_str!("input.txt");
+    const ANSWERS: &'static str = include_str!("answers.txt");
+
+    type Input = Vec<Vec<bool>>;
+
+    fn parse(input: &str) -> error::Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(report: &Self::Input) -> error::Result<Answer> {
+        part1(report).map(Answer::from)
+    }
+
+    fn part2(report: &Self::Input) -> error::Result<Answer> {
+        part2(report).map(Answer::from)
+    }
+}
+
+/// Parses the report as one binary number per line, most significant bit
+/// first.
+pub fn parse(input: &str) -> error::Result<Vec<Vec<bool>>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(line_index, line)| {
+            line.char_indices()
+                .map(|(position, char)| match char {
+                    '0' => Ok(false),
+                    '1' => Ok(true),
+                    _ => Err(Error::new(format!("unexpected char {:?}", char))
+                        .at_line(line_index + 1)
+                        .at_column(position + 1)),
+                })
+                .collect()
+        })
+        .collect()
+}
+
+pub fn part1(report: &[Vec<bool>]) -> error::Result<usize> {
+    let positions_counts = positions_counts(report);
+
+    if positions_counts.is_empty() {
+        return Err(Error::new("empty diagnostic report"));
+    }
+
+    let mut gamma = 0;
+    let mut epsilon = 0;
+
+    for counts in positions_counts.into_iter() {
+        gamma <<= 1;
+        epsilon <<= 1;
+
+        if BitCriteria::MostCommon.bit(counts) {
+            gamma += 1;
+        } else {
+            epsilon += 1;
+        }
+    }
+
+    Ok(gamma * epsilon)
+}
+
+pub fn part2(report: &[Vec<bool>]) -> error::Result<usize> {
+    let oxygen = rating(report, BitCriteria::MostCommon)?;
+    let co2 = rating(report, BitCriteria::LeastCommon)?;
+
+    Ok(oxygen * co2)
+}
+
+/// Which bit to keep at a position, given how many numbers have a zero and a
+/// one there.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BitCriteria {
+    /// Ties keep ones.
+    MostCommon,
+    /// Ties keep zeroes.
+    LeastCommon,
+}
+
+impl BitCriteria {
+    pub fn bit(self, (zeroes, ones): (usize, usize)) -> bool {
+        match self {
+            Self::MostCommon => ones >= zeroes,
+            Self::LeastCommon => ones < zeroes,
+        }
+    }
+}
+
+/// Filters the report one position at a time, keeping the numbers whose bit
+/// matches `criteria` among the remaining ones, until a single number is left.
+///
+/// Positions where every remaining number has the same bit keep them all.
+pub fn rating(report: &[Vec<bool>], criteria: BitCriteria) -> error::Result<usize> {
+    if report.is_empty() {
+        return Err(Error::new("empty diagnostic report"));
+    }
+
+    let mut remaining: Vec<&Vec<bool>> = report.iter().collect();
+    let mut position = 0;
+
+    while remaining.len() > 1 {
+        let counts = positions_counts(remaining.iter().copied())
+            .get(position)
+            .copied()
+            .ok_or_else(|| Error::new(format!("no single number matches {:?}", criteria)))?;
+        let bit = criteria.bit(counts);
+
+        if counts.0 > 0 && counts.1 > 0 {
+            remaining.retain(|number| number.get(position) == Some(&bit));
+        }
+
+        position += 1;
+    }
+
+    Ok(to_decimal(remaining[0]))
+}
+
+/// Number of zeroes and ones at each position of `numbers`.
+fn positions_counts<'a, I>(numbers: I) -> Vec<(usize, usize)>
+where
+    I: IntoIterator<Item = &'a Vec<bool>>,
+{
+    let mut positions_counts: Vec<(usize, usize)> = Vec::new();
+
+    for number in numbers {
+        if positions_counts.len() < number.len() {
+            positions_counts.resize(number.len(), (0, 0));
+        }
+
+        for (position, bit) in number.iter().enumerate() {
+            let (zeroes, ones) = &mut positions_counts[position];
+
+            if *bit {
+                *ones += 1;
+            } else {
+                *zeroes += 1;
+            }
+        }
+    }
+
+    positions_counts
+}
+
+fn to_decimal(number: &[bool]) -> usize {
+    number
+        .iter()
+        .fold(0, |decimal, bit| (decimal << 1) + usize::from(*bit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+00100
+11110
+10110
+10111
+10101
+01111
+00111
+11100
+10000
+11001
+00010
+01010
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Ok(198), part1(&parse(EXAMPLE).unwrap()));
+    }
+
+    #[test]
+    fn part2_example() {
+        let report = parse(EXAMPLE).unwrap();
+
+        assert_eq!(Ok(23), rating(&report, BitCriteria::MostCommon));
+        assert_eq!(Ok(10), rating(&report, BitCriteria::LeastCommon));
+        assert_eq!(Ok(230), part2(&report));
+    }
+
+    #[test]
+    fn shared_bits() {
+        let report = parse("00\n01\n").unwrap();
+
+        assert_eq!(rating(&report, BitCriteria::MostCommon), Ok(1));
+        assert_eq!(rating(&report, BitCriteria::LeastCommon), Ok(0));
+        assert_eq!(part2(&report), Ok(0));
+        assert!(rating(&parse("01\n01\n").unwrap(), BitCriteria::LeastCommon).is_err());
+        assert_eq!(
+            rating(&[], BitCriteria::MostCommon).unwrap_err().message(),
+            "empty diagnostic report"
+        );
+    }
+
+    #[test]
+    fn bit_criteria_ties() {
+        assert!(BitCriteria::MostCommon.bit((2, 2)));
+        assert!(!BitCriteria::LeastCommon.bit((2, 2)));
+        assert!(!BitCriteria::MostCommon.bit((3, 1)));
+        assert!(BitCriteria::LeastCommon.bit((3, 1)));
+    }
+
+    #[test]
+    fn invalid_report() {
+        let err = parse("0101\n01a1\n").unwrap_err();
+
+        assert_eq!((err.line(), err.column()), (Some(2), Some(3)));
+        assert!(part1(&[]).is_err());
+        assert!(part2(&[]).is_err());
+
+        let duplicated = parse("101\n101\n").unwrap();
+
+        assert!(rating(&duplicated, BitCriteria::MostCommon).is_err());
+    }
+}
@@ -0,0 +1,227 @@
+//! Plays a bingo game turn by turn, reporting what happens along the way.
+
+use super::bingo::{Board, Draw, WinRule};
+use std::collections::VecDeque;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Event {
+    /// `number` was drawn at `turn`, turns starting at 1.
+    Drawn {
+        turn: usize,
+        number: u64,
+    },
+    /// The cell at `x`/`y` of the board at index `board` held the drawn
+    /// number.
+    Marked {
+        turn: usize,
+        board: usize,
+        x: usize,
+        y: usize,
+    },
+    Won(Win),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Win {
+    pub turn: usize,
+    pub board: usize,
+    /// 1 for the first board to win, boards winning on the same turn being
+    /// ranked by index.
+    pub rank: usize,
+    /// Sum of the unmarked numbers times the winning number.
+    pub score: u64,
+}
+
+/// A game iterating over its events. Boards keep being marked after they
+/// won, but win only once.
+pub struct Game<'a> {
+    draw: &'a Draw,
+    boards: Vec<Board>,
+    rule: WinRule,
+    turn: usize,
+    won: Vec<bool>,
+    winners: Vec<Win>,
+    pending: VecDeque<Event>,
+}
+
+impl<'a> Game<'a> {
+    pub fn new(draw: &'a Draw, boards: Vec<Board>, rule: WinRule) -> Self {
+        Self {
+            draw,
+            won: vec![false; boards.len()],
+            boards,
+            rule,
+            turn: 0,
+            winners: Vec::new(),
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Number of numbers drawn so far.
+    pub fn turn(&self) -> usize {
+        self.turn
+    }
+
+    pub fn boards(&self) -> &[Board] {
+        &self.boards
+    }
+
+    pub fn board(&self, index: usize) -> Option<&Board> {
+        self.boards.get(index)
+    }
+
+    /// Boards that won so far, in ranking order.
+    pub fn winners(&self) -> &[Win] {
+        &self.winners
+    }
+
+    /// Draws the next number and marks the boards, returning the events of
+    /// the turn or `None` once the draw is exhausted.
+    pub fn play_turn(&mut self) -> Option<Vec<Event>> {
+        let number = self.draw.get(self.turn)?;
+
+        self.turn += 1;
+
+        let turn = self.turn;
+        let mut events = vec![Event::Drawn { turn, number }];
+
+        for (index, board) in self.boards.iter_mut().enumerate() {
+            for (x, y) in board.mark(number) {
+                events.push(Event::Marked {
+                    turn,
+                    board: index,
+                    x,
+                    y,
+                });
+            }
+
+            if !self.won[index] && board.won(&self.rule) {
+                let win = Win {
+                    turn,
+                    board: index,
+                    rank: self.winners.len() + 1,
+                    score: board.score() * number,
+                };
+
+                self.won[index] = true;
+                self.winners.push(win.clone());
+                events.push(Event::Won(win));
+            }
+        }
+
+        Some(events)
+    }
+
+    /// Plays until `turn` numbers have been drawn, or the draw is exhausted.
+    pub fn play_until(&mut self, turn: usize) {
+        while self.turn < turn && self.play_turn().is_some() {}
+    }
+
+    /// Plays until every board won or the draw is exhausted, returning the
+    /// final ranking.
+    pub fn play_all(mut self) -> Vec<Win> {
+        while self.winners.len() < self.boards.len() && self.play_turn().is_some() {}
+
+        self.winners
+    }
+}
+
+impl Iterator for Game<'_> {
+    type Item = Event;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.pending.is_empty() {
+            let events = self.play_turn()?;
+            self.pending.extend(events);
+        }
+
+        self.pending.pop_front()
+    }
+}
+
+/// State of the board at index `board` once `turn` numbers have been drawn.
+pub fn board_at(draw: &Draw, boards: &[Board], board: usize, turn: usize) -> Option<Board> {
+    let mut board = boards.get(board)?.clone();
+
+    for number in draw.iter().take(turn) {
+        board.mark(*number);
+    }
+
+    Some(board)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{parse_input, tests::EXAMPLE};
+    use super::*;
+
+    #[test]
+    fn example_events() {
+        let (draw, boards) = parse_input(EXAMPLE).unwrap();
+        let events: Vec<Event> = Game::new(&draw, boards, WinRule::lines()).collect();
+
+        assert_eq!(events[0], Event::Drawn { turn: 1, number: 7 });
+        assert_eq!(
+            events[1],
+            Event::Marked {
+                turn: 1,
+                board: 0,
+                x: 4,
+                y: 2
+            }
+        );
+
+        let wins: Vec<&Win> = events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Won(win) => Some(win),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(
+            wins,
+            vec![
+                &Win {
+                    turn: 12,
+                    board: 2,
+                    rank: 1,
+                    score: 4512
+                },
+                &Win {
+                    turn: 14,
+                    board: 0,
+                    rank: 2,
+                    score: 2192
+                },
+                &Win {
+                    turn: 15,
+                    board: 1,
+                    rank: 3,
+                    score: 1924
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn play_until_turn() {
+        let (draw, boards) = parse_input(EXAMPLE).unwrap();
+        let mut game = Game::new(&draw, boards.clone(), WinRule::lines());
+
+        game.play_until(12);
+
+        assert_eq!(game.turn(), 12);
+        assert_eq!(game.winners().len(), 1);
+        assert_eq!(
+            game.board(2).unwrap().to_string(),
+            "x x x x x\n10 16 15 x 19\n18 8 x 26 20\n22 x 13 6 x\nx x 12 3 x\n"
+        );
+        assert_eq!(
+            board_at(&draw, &boards, 2, 12).unwrap().to_string(),
+            game.board(2).unwrap().to_string()
+        );
+
+        assert_eq!(game.play_all().len(), 3);
+    }
+}
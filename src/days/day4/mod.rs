@@ -0,0 +1,829 @@
+use crate::error::{self, Error};
+use crate::solution::{Answer, Solution};
+use game::Game;
+
+pub mod game;
+pub mod odds;
+pub mod validation;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    const INPUT: &'static str = include_str!("input.txt");
+    const ANSWERS: &'static str = include_str!("answers.txt");
+
+    type Input = (bingo::Draw, Vec<bingo::Board>);
+
+    fn parse(input: &str) -> error::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1((draw, boards): &Self::Input) -> error::Result<Answer> {
+        part1(draw, boards.clone(), &bingo::WinRule::lines()).map(Answer::from)
+    }
+
+    fn part2((draw, boards): &Self::Input) -> error::Result<Answer> {
+        part2(draw, boards.clone(), &bingo::WinRule::lines()).map(Answer::from)
+    }
+
+    /// The issues found by [`validation::validate`], which boards handle
+    /// fine but may keep them from winning.
+    fn warnings((draw, boards): &Self::Input) -> Vec<Error> {
+        validation::validate(draw, boards)
+            .into_iter()
+            .map(|issue| {
+                let line = validation::line_of(&issue, boards);
+
+                Error::new(issue.to_string()).at_line(line)
+            })
+            .collect()
+    }
+}
+
+pub fn parse_input(input: &str) -> error::Result<(bingo::Draw, Vec<bingo::Board>)> {
+    let mut lines = input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line));
+
+    let draw_line = lines
+        .next()
+        .ok_or_else(|| Error::new("invalid input: no draw"))?
+        .1;
+
+    let draw =
+        bingo::Draw::from(error::parse_separated(draw_line, ',').map_err(|err| err.at_line(1))?);
+
+    match lines.next() {
+        None => return Err(Error::new("no boards after draw").at_line(2)),
+        Some((_, "")) => (),
+        Some((line, _)) => return Err(Error::new("line after draw should be empty").at_line(line)),
+    }
+
+    let mut boards: Vec<bingo::Board> = Vec::new();
+
+    loop {
+        let board_lines: Vec<(usize, &str)> = lines
+            .by_ref()
+            .take_while(|(_, line)| !line.is_empty())
+            .collect();
+
+        let first_line = match board_lines.first() {
+            Some((line, _)) => *line,
+            None => break,
+        };
+
+        let board_str = board_lines
+            .iter()
+            .map(|(_, line)| *line)
+            .collect::<Vec<&str>>()
+            .join("\n");
+
+        let board = board_str.parse().map_err(|err: bingo::ParseBoardError| {
+            let error = Error::new(format!("invalid board ({})", err));
+
+            match err {
+                bingo::ParseBoardError::RaggedRow { row, .. } => error.at_line(first_line + row),
+                bingo::ParseBoardError::InvalidNumber { row, column, .. } => {
+                    error.at_line(first_line + row).at_column(column + 1)
+                }
+                _ => error.at_line(first_line),
+            }
+        })?;
+
+        boards.push(board);
+    }
+
+    Ok((draw, boards))
+}
+
+/// Score of the first board to win under `rule`.
+pub fn part1(
+    draw: &bingo::Draw,
+    boards: Vec<bingo::Board>,
+    rule: &bingo::WinRule,
+) -> error::Result<u64> {
+    let mut game = Game::new(draw, boards, rule.clone());
+
+    while game.winners().is_empty() {
+        if game.play_turn().is_none() {
+            return Err(Error::new("the draw is exhausted before any board wins"));
+        }
+    }
+
+    Ok(game.winners()[0].score)
+}
+
+/// Score of the last board to win under `rule`.
+pub fn part2(
+    draw: &bingo::Draw,
+    boards: Vec<bingo::Board>,
+    rule: &bingo::WinRule,
+) -> error::Result<u64> {
+    let boards_len = boards.len();
+    let winners = Game::new(draw, boards, rule.clone()).play_all();
+
+    match winners.last() {
+        Some(last) if winners.len() == boards_len => Ok(last.score),
+        _ => Err(Error::new(format!(
+            "the draw is exhausted before every board wins, {} of {} won",
+            winners.len(),
+            boards_len
+        ))),
+    }
+}
+
+pub mod bingo {
+    use crate::grid::{Grid, GridError};
+    use std::{
+        collections::HashMap,
+        fmt::{Display, Formatter},
+        num::ParseIntError,
+        str::FromStr,
+    };
+
+    #[derive(Debug)]
+    pub struct Draw(Vec<u64>);
+
+    impl Draw {
+        pub fn iter(&self) -> std::slice::Iter<'_, u64> {
+            self.0.iter()
+        }
+
+        pub fn get(&self, index: usize) -> Option<u64> {
+            self.0.get(index).copied()
+        }
+
+        pub fn len(&self) -> usize {
+            self.0.len()
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.0.is_empty()
+        }
+    }
+
+    impl From<Vec<u64>> for Draw {
+        fn from(numbers: Vec<u64>) -> Self {
+            Draw(numbers)
+        }
+    }
+
+    impl FromStr for Draw {
+        type Err = ParseIntError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Ok(Draw(
+                s.trim()
+                    .split(',')
+                    .map(|part| part.parse::<u64>())
+                    .collect::<Result<Vec<u64>, ParseIntError>>()?,
+            ))
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    enum BoardCell {
+        Number(u64),
+        Marked,
+    }
+
+    impl BoardCell {
+        fn is_marked(&self) -> bool {
+            matches!(self, Self::Marked)
+        }
+
+        /// Marks the cell, returning its number when it was not marked yet.
+        fn mark(&mut self) -> Option<u64> {
+            match *self {
+                Self::Number(n) => {
+                    *self = Self::Marked;
+
+                    Some(n)
+                }
+                Self::Marked => None,
+            }
+        }
+    }
+
+    impl Display for BoardCell {
+        fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+            match self {
+                Self::Number(n) => write!(f, "{}", n)?,
+                Self::Marked => write!(f, "x")?,
+            };
+
+            Ok(())
+        }
+    }
+
+    impl FromStr for BoardCell {
+        type Err = ParseIntError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Ok(BoardCell::Number(s.parse()?))
+        }
+    }
+
+    /// A bingo board of any size, its dimensions being inferred from its
+    /// rows.
+    ///
+    /// Boards index the positions of their numbers and count the marked cells
+    /// of each row and column, so that marking a number and checking for a
+    /// full row or column do not need to scan the board.
+    #[derive(Clone, Debug)]
+    pub struct Board {
+        cells: Grid<BoardCell>,
+        positions: HashMap<u64, Vec<usize>>,
+        marked_in_rows: Vec<usize>,
+        marked_in_columns: Vec<usize>,
+        full_rows: usize,
+        full_columns: usize,
+        marked: usize,
+        unmarked_sum: u64,
+    }
+
+    impl Display for Board {
+        fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+            for row in self.cells.rows() {
+                let row: Vec<String> = row.iter().map(|cell| cell.to_string()).collect();
+
+                writeln!(f, "{}", row.join(" "))?;
+            }
+
+            Ok(())
+        }
+    }
+
+    impl Board {
+        fn new(cells: Grid<BoardCell>) -> Self {
+            let mut positions: HashMap<u64, Vec<usize>> = HashMap::new();
+            let mut unmarked_sum = 0;
+
+            for (index, cell) in cells.cells().iter().enumerate() {
+                if let BoardCell::Number(n) = cell {
+                    positions.entry(*n).or_default().push(index);
+                    unmarked_sum += n;
+                }
+            }
+
+            Self {
+                marked_in_rows: vec![0; cells.height()],
+                marked_in_columns: vec![0; cells.width()],
+                cells,
+                positions,
+                full_rows: 0,
+                full_columns: 0,
+                marked: 0,
+                unmarked_sum,
+            }
+        }
+
+        pub fn width(&self) -> usize {
+            self.cells.width()
+        }
+
+        pub fn height(&self) -> usize {
+            self.cells.height()
+        }
+
+        /// Marks every cell holding `number`, returning the `(x, y)`
+        /// coordinates of the cells that were not marked yet.
+        pub fn mark(&mut self, number: u64) -> Vec<(usize, usize)> {
+            let positions = match self.positions.get(&number) {
+                Some(positions) => positions,
+                None => return Vec::new(),
+            };
+
+            let mut marked = Vec::new();
+
+            for index in positions {
+                if let Some(n) = self.cells[*index].mark() {
+                    let (x, y) = self.cells.coords_of(*index).expect("indexed cell");
+
+                    self.marked_in_rows[y] += 1;
+                    self.marked_in_columns[x] += 1;
+
+                    if self.marked_in_rows[y] == self.cells.width() {
+                        self.full_rows += 1;
+                    }
+
+                    if self.marked_in_columns[x] == self.cells.height() {
+                        self.full_columns += 1;
+                    }
+
+                    self.marked += 1;
+                    self.unmarked_sum -= n;
+                    marked.push((x, y));
+                }
+            }
+
+            marked
+        }
+
+        /// Constant time for rows, columns and blackout, other rules looking
+        /// at the cells of their patterns.
+        pub fn won(&self, rule: &WinRule) -> bool {
+            match rule {
+                WinRule::Rows => self.full_rows > 0,
+                WinRule::Columns => self.full_columns > 0,
+                WinRule::Blackout => self.marked == self.cells.len(),
+                WinRule::Any(rules) => rules.iter().any(|rule| self.won(rule)),
+                _ => rule
+                    .patterns(self.width(), self.height())
+                    .iter()
+                    .any(|pattern| pattern.iter().all(|index| self.cells[*index].is_marked())),
+            }
+        }
+
+        /// `(x, y)` coordinates and number of each cell not marked yet, row
+        /// by row.
+        pub fn unmarked(&self) -> impl Iterator<Item = ((usize, usize), u64)> + '_ {
+            self.cells
+                .cells()
+                .iter()
+                .enumerate()
+                .filter_map(move |(index, cell)| match cell {
+                    BoardCell::Number(n) => Some((self.cells.coords_of(index)?, *n)),
+                    BoardCell::Marked => None,
+                })
+        }
+
+        /// Sum of the unmarked numbers.
+        pub fn score(&self) -> u64 {
+            self.unmarked_sum
+        }
+    }
+
+    /// Which sets of cells a board needs to have marked to win.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum WinRule {
+        Rows,
+        Columns,
+        /// Both diagonals of square boards, other boards having none.
+        Diagonals,
+        FourCorners,
+        /// Every cell of the board.
+        Blackout,
+        /// Custom patterns, each given as the `(x, y)` coordinates of its
+        /// cells. Patterns not fitting in a board can never be completed on
+        /// it, and neither can empty ones.
+        Masks(Vec<Vec<(usize, usize)>>),
+        /// Wins as soon as one of the rules is fulfilled.
+        Any(Vec<WinRule>),
+    }
+
+    impl WinRule {
+        /// Full rows or columns, as played in the puzzle.
+        pub fn lines() -> Self {
+            Self::Any(vec![Self::Rows, Self::Columns])
+        }
+
+        /// Flat indexes of the cells of each winning pattern on a board of
+        /// `width` by `height` cells.
+        pub fn patterns(&self, width: usize, height: usize) -> Vec<Vec<usize>> {
+            let index = |x: usize, y: usize| y * width + x;
+
+            match self {
+                Self::Rows => (0..height)
+                    .map(|y| (0..width).map(|x| index(x, y)).collect())
+                    .collect(),
+                Self::Columns => (0..width)
+                    .map(|x| (0..height).map(|y| index(x, y)).collect())
+                    .collect(),
+                Self::Diagonals if width == height && width > 0 => vec![
+                    (0..width).map(|i| index(i, i)).collect(),
+                    (0..width).map(|i| index(width - 1 - i, i)).collect(),
+                ],
+                Self::Diagonals => Vec::new(),
+                Self::FourCorners if width > 0 && height > 0 => vec![vec![
+                    index(0, 0),
+                    index(width - 1, 0),
+                    index(0, height - 1),
+                    index(width - 1, height - 1),
+                ]],
+                Self::FourCorners => Vec::new(),
+                Self::Blackout => vec![(0..width * height).collect()],
+                Self::Masks(masks) => masks
+                    .iter()
+                    .filter(|mask| {
+                        !mask.is_empty() && mask.iter().all(|(x, y)| *x < width && *y < height)
+                    })
+                    .map(|mask| mask.iter().map(|(x, y)| index(*x, *y)).collect())
+                    .collect(),
+                Self::Any(rules) => rules
+                    .iter()
+                    .flat_map(|rule| rule.patterns(width, height))
+                    .collect(),
+            }
+        }
+    }
+
+    impl Default for WinRule {
+        fn default() -> Self {
+            Self::lines()
+        }
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct ParseWinRuleError(String);
+
+    impl Display for ParseWinRuleError {
+        fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+            write!(
+                f,
+                "unknown rule {:?}, expected rows, columns, lines, diagonals, corners or blackout",
+                self.0
+            )
+        }
+    }
+
+    /// Parses rule names joined with `+`, such as `lines+diagonals`. Masks
+    /// have no name.
+    impl FromStr for WinRule {
+        type Err = ParseWinRuleError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let mut rules = s
+                .split('+')
+                .map(|name| match name.trim() {
+                    "rows" => Ok(Self::Rows),
+                    "columns" => Ok(Self::Columns),
+                    "lines" => Ok(Self::lines()),
+                    "diagonals" => Ok(Self::Diagonals),
+                    "corners" => Ok(Self::FourCorners),
+                    "blackout" => Ok(Self::Blackout),
+                    name => Err(ParseWinRuleError(name.to_string())),
+                })
+                .collect::<Result<Vec<Self>, Self::Err>>()?;
+
+            Ok(if rules.len() == 1 {
+                rules.remove(0)
+            } else {
+                Self::Any(rules)
+            })
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub enum ParseBoardError {
+        Empty,
+        /// The row at `row` (0-based) does not have as many numbers as the
+        /// first one.
+        RaggedRow {
+            row: usize,
+            expected: usize,
+            actual: usize,
+        },
+        /// The number starting at `row`/`column` (0-based, in characters) is
+        /// invalid.
+        InvalidNumber {
+            row: usize,
+            column: usize,
+            err: ParseIntError,
+        },
+        SizeMismatch(GridError),
+    }
+
+    impl Display for ParseBoardError {
+        fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+            match self {
+                Self::Empty => write!(f, "empty board"),
+                Self::RaggedRow {
+                    expected, actual, ..
+                } => write!(
+                    f,
+                    "row has {} numbers while the first one has {}",
+                    actual, expected
+                ),
+                Self::InvalidNumber { err, .. } => write!(f, "{}", err),
+                Self::SizeMismatch(GridError::SizeMismatch { expected, actual }) => {
+                    write!(f, "expected {} numbers, got {}", expected, actual)
+                }
+            }
+        }
+    }
+
+    impl FromStr for Board {
+        type Err = ParseBoardError;
+
+        /// Parses one row of whitespace separated numbers per line.
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let mut width = None;
+            let mut height = 0;
+            let mut cells = Vec::new();
+
+            for (row, line) in s.lines().enumerate() {
+                let numbers = numbers_with_columns(line);
+
+                if *width.get_or_insert(numbers.len()) != numbers.len() {
+                    return Err(ParseBoardError::RaggedRow {
+                        row,
+                        expected: width.unwrap_or_default(),
+                        actual: numbers.len(),
+                    });
+                }
+
+                for (column, number) in numbers {
+                    let cell = number
+                        .parse()
+                        .map_err(|err| ParseBoardError::InvalidNumber { row, column, err })?;
+
+                    cells.push(cell);
+                }
+
+                height += 1;
+            }
+
+            match width {
+                None | Some(0) => Err(ParseBoardError::Empty),
+                Some(width) => Grid::new(width, height, cells)
+                    .map(Board::new)
+                    .map_err(ParseBoardError::SizeMismatch),
+            }
+        }
+    }
+
+    /// Splits `line` on whitespace, along with the 0-based column of each
+    /// value.
+    fn numbers_with_columns(line: &str) -> Vec<(usize, &str)> {
+        let mut numbers = Vec::new();
+        let mut start = None;
+
+        for (column, c) in line.char_indices().chain([(line.len(), ' ')]) {
+            match (start, c.is_whitespace()) {
+                (None, false) => start = Some(column),
+                (Some(number_start), true) => {
+                    numbers.push((
+                        line[..number_start].chars().count(),
+                        &line[number_start..column],
+                    ));
+                    start = None;
+                }
+                _ => (),
+            }
+        }
+
+        numbers
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn parse_draw() {
+            let draw: Draw = "1,2,3".parse().expect("Parsing failed");
+            assert_eq!(draw.0.len(), 3);
+        }
+
+        #[test]
+        fn parse_board() {
+            let board_lines = "\
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19";
+            let board: Board = board_lines.parse().expect("Parsing failed");
+
+            assert_eq!((board.width(), board.height()), (5, 5));
+            assert_eq!(
+                board.to_string(),
+                "22 13 17 11 0\n8 2 23 4 24\n21 9 14 16 7\n6 10 3 18 5\n1 12 20 15 19\n"
+            );
+        }
+
+        #[test]
+        fn parse_rectangular_board() {
+            let mut board: Board = "1 2 3\n4 5 6\n".parse().unwrap();
+
+            assert_eq!((board.width(), board.height()), (3, 2));
+
+            board.mark(2);
+            board.mark(5);
+
+            assert!(board.won(&WinRule::lines()));
+            assert_eq!(board.score(), 14);
+            assert!(board.mark(5).is_empty());
+            assert!(board.mark(42).is_empty());
+            assert_eq!(board.score(), 14);
+        }
+
+        fn marked(board: &str, numbers: &[u64]) -> Board {
+            let mut board: Board = board.parse().unwrap();
+
+            for n in numbers {
+                board.mark(*n);
+            }
+
+            board
+        }
+
+        #[test]
+        fn win_rules() {
+            let board = "1 2 3\n4 5 6\n7 8 9\n";
+
+            let row = marked(board, &[4, 5, 6]);
+            assert!(row.won(&WinRule::Rows));
+            assert!(!row.won(&WinRule::Columns));
+            assert!(row.won(&WinRule::lines()));
+
+            let column = marked(board, &[2, 5, 8]);
+            assert!(column.won(&WinRule::Columns));
+            assert!(!column.won(&WinRule::Rows));
+
+            let diagonal = marked(board, &[3, 5, 7]);
+            assert!(diagonal.won(&WinRule::Diagonals));
+            assert!(!diagonal.won(&WinRule::lines()));
+
+            let corners = marked(board, &[1, 3, 7, 9]);
+            assert!(corners.won(&WinRule::FourCorners));
+            assert!(!corners.won(&WinRule::Diagonals));
+
+            let all_but_one = marked(board, &[1, 2, 3, 4, 5, 6, 7, 8]);
+            assert!(!all_but_one.won(&WinRule::Blackout));
+            assert!(marked(board, &[1, 2, 3, 4, 5, 6, 7, 8, 9]).won(&WinRule::Blackout));
+
+            let cross = WinRule::Masks(vec![vec![(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)]]);
+            assert!(marked(board, &[2, 4, 5, 6, 8]).won(&cross));
+            assert!(!row.won(&cross));
+        }
+
+        #[test]
+        fn counters_match_patterns() {
+            let rules = [WinRule::Rows, WinRule::Columns, WinRule::Blackout];
+            let mut board: Board = "1 2 3 4\n5 6 7 8\n9 1 2 3\n".parse().unwrap();
+
+            for n in [6, 2, 1, 7, 3, 5, 8, 4, 9] {
+                board.mark(n);
+
+                for rule in &rules {
+                    let scanned = rule
+                        .patterns(board.width(), board.height())
+                        .iter()
+                        .any(|pattern| pattern.iter().all(|i| board.cells[*i].is_marked()));
+
+                    assert_eq!(board.won(rule), scanned, "{:?} after {}", rule, n);
+                }
+            }
+
+            assert_eq!(board.score(), 0);
+        }
+
+        #[test]
+        fn rules_on_rectangular_boards() {
+            let board = marked("1 2 3\n4 5 6\n", &[1, 3, 4, 6]);
+
+            assert!(board.won(&WinRule::FourCorners));
+            assert!(!board.won(&WinRule::Rows));
+            assert!(WinRule::Diagonals.patterns(3, 2).is_empty());
+            assert!(!board.won(&WinRule::Masks(vec![vec![(0, 0), (0, 2)]])));
+        }
+
+        #[test]
+        fn parse_invalid_board() {
+            assert_eq!("".parse::<Board>().unwrap_err(), ParseBoardError::Empty);
+            assert_eq!(
+                "1 2 3\n4 5\n".parse::<Board>().unwrap_err(),
+                ParseBoardError::RaggedRow {
+                    row: 1,
+                    expected: 3,
+                    actual: 2
+                }
+            );
+            assert!(matches!(
+                "1 2\n3  x\n".parse::<Board>().unwrap_err(),
+                ParseBoardError::InvalidNumber {
+                    row: 1,
+                    column: 3,
+                    ..
+                }
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{DynSolution, Part};
+
+    pub(super) const EXAMPLE: &str = "\
+7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7
+";
+
+    #[test]
+    fn part1_example() {
+        let (draw, boards) = parse_input(EXAMPLE).unwrap();
+
+        assert_eq!(Ok(4512), part1(&draw, boards, &bingo::WinRule::lines()));
+    }
+
+    #[test]
+    fn part2_example() {
+        let (draw, boards) = parse_input(EXAMPLE).unwrap();
+
+        assert_eq!(Ok(1924), part2(&draw, boards, &bingo::WinRule::lines()));
+    }
+
+    #[test]
+    fn other_rules_example() {
+        let (draw, boards) = parse_input(EXAMPLE).unwrap();
+
+        // The third board gets its four corners 14, 4, 2 and 7 marked when 14
+        // is drawn, leaving 233 unmarked.
+        assert_eq!(
+            Ok(233 * 14),
+            part1(&draw, boards.clone(), &bingo::WinRule::FourCorners)
+        );
+        // Every number ends up drawn, leaving nothing unmarked.
+        assert_eq!(Ok(0), part1(&draw, boards, &bingo::WinRule::Blackout));
+    }
+
+    #[test]
+    fn empty_masks() {
+        let (draw, boards) = parse_input("9,8\n\n1 2\n3 4\n").unwrap();
+        let empty = bingo::WinRule::Masks(vec![vec![]]);
+
+        assert!(empty.patterns(2, 2).is_empty());
+        assert!(part1(&draw, boards, &empty).is_err());
+    }
+
+    #[test]
+    fn parse_win_rules() {
+        assert_eq!("lines".parse(), Ok(bingo::WinRule::lines()));
+        assert_eq!("corners".parse(), Ok(bingo::WinRule::FourCorners));
+        assert_eq!(
+            "rows + diagonals".parse(),
+            Ok(bingo::WinRule::Any(vec![
+                bingo::WinRule::Rows,
+                bingo::WinRule::Diagonals
+            ]))
+        );
+        assert!("rows+stars".parse::<bingo::WinRule>().is_err());
+    }
+
+    #[test]
+    fn invalid_input() {
+        let err = parse_input("7,4,x,5\n\n1 2\n3 4\n").unwrap_err();
+        assert_eq!((err.line(), err.column()), (Some(1), Some(5)));
+
+        let err = parse_input("7,4\n\n1 2\n3 4\n\n1 2\n3 x\n").unwrap_err();
+        assert_eq!((err.line(), err.column()), (Some(7), Some(3)));
+
+        let err = parse_input("7,4\n\n1 2\n3 4 5\n").unwrap_err();
+        assert_eq!(err.line(), Some(4));
+
+        let (draw, boards) = parse_input("7,4\n\n1 2\n3 4\n").unwrap();
+        assert!(part1(&draw, boards.clone(), &bingo::WinRule::lines()).is_err());
+        assert!(part2(&draw, boards, &bingo::WinRule::lines()).is_err());
+
+        let (draw, boards) = parse_input("1,2,3\n\n1 2\n3 1\n\n4 5\n6 7\n").unwrap();
+        assert_eq!(
+            part2(&draw, boards, &bingo::WinRule::lines())
+                .unwrap_err()
+                .message(),
+            "the draw is exhausted before every board wins, 1 of 2 won"
+        );
+    }
+
+    #[test]
+    fn issues_are_warnings() {
+        let input = "7,4,9\n\n1 2\n3 4\n\n7 4\n9 5\n";
+        let runs = Day4.run(input, &Part::ALL).unwrap();
+
+        assert_eq!(runs[0].answer, Ok(Answer::Number(56)));
+        assert_eq!(
+            runs[1].answer.as_ref().unwrap_err().message(),
+            "the draw is exhausted before every board wins, 1 of 2 won"
+        );
+
+        let warnings = Day4.warnings(input).unwrap();
+
+        assert_eq!(warnings.len(), 4);
+        assert_eq!(
+            warnings[0].to_string(),
+            "day 4: line 3: board 0: 1 at (0, 0) is never drawn"
+        );
+    }
+}
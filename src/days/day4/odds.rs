@@ -0,0 +1,218 @@
+//! Estimates the odds of each board by playing many random draws.
+//!
+//! Rather than marking the boards turn by turn, each game looks up the turn
+//! every number is drawn at: a pattern is completed on the turn its last
+//! number is drawn, and a board wins with its earliest pattern.
+
+use super::bingo::{Board, Draw, WinRule};
+use crate::rng::Rng;
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Odds {
+    pub board: usize,
+    /// Share of the games in which the board won on the turn of the first
+    /// win, ties counting as a win for every board involved.
+    pub first: f64,
+    /// Share of the games in which the board won on the turn of the last win.
+    pub last: f64,
+    /// Share of the games in which the board won at all.
+    pub wins: f64,
+    /// Average number of draws the board needed to win, over the games it
+    /// won. `None` when it never won.
+    pub expected_draws: Option<f64>,
+}
+
+/// Plays `games` games with random permutations of the numbers of `draw`,
+/// reproducible from `seed`.
+pub fn estimate(
+    draw: &Draw,
+    boards: &[Board],
+    rule: &WinRule,
+    games: usize,
+    seed: u64,
+) -> Vec<Odds> {
+    let mut rng = Rng::new(seed);
+    let mut numbers: Vec<u64> = draw.iter().copied().collect();
+
+    // Numbers are replaced by their slot among the distinct drawn numbers.
+    let mut slots: HashMap<u64, usize> = HashMap::new();
+
+    for number in &numbers {
+        let len = slots.len();
+        slots.entry(*number).or_insert(len);
+    }
+
+    let patterns: Vec<Vec<Vec<usize>>> = boards
+        .iter()
+        .map(|board| board_patterns(board, rule, &slots))
+        .collect();
+
+    let mut firsts = vec![0_usize; boards.len()];
+    let mut lasts = vec![0_usize; boards.len()];
+    let mut wins = vec![0_usize; boards.len()];
+    let mut draws = vec![0_usize; boards.len()];
+    let mut turns = vec![usize::MAX; slots.len()];
+    let mut win_turns: Vec<Option<usize>> = vec![None; boards.len()];
+
+    for _ in 0..games {
+        rng.shuffle(&mut numbers);
+
+        turns.fill(usize::MAX);
+
+        for (index, number) in numbers.iter().enumerate() {
+            let turn = &mut turns[slots[number]];
+            *turn = (*turn).min(index + 1);
+        }
+
+        for (win_turn, patterns) in win_turns.iter_mut().zip(&patterns) {
+            *win_turn = patterns
+                .iter()
+                .map(|pattern| {
+                    pattern
+                        .iter()
+                        .map(|slot| turns[*slot])
+                        .max()
+                        .unwrap_or_default()
+                        .max(1)
+                })
+                .min();
+        }
+
+        let (first_turn, last_turn) = match (
+            win_turns.iter().flatten().min(),
+            win_turns.iter().flatten().max(),
+        ) {
+            (Some(first), Some(last)) => (*first, *last),
+            _ => continue,
+        };
+
+        for (board, win_turn) in win_turns.iter().enumerate() {
+            let turn = match win_turn {
+                Some(turn) => *turn,
+                None => continue,
+            };
+
+            wins[board] += 1;
+            draws[board] += turn;
+
+            if turn == first_turn {
+                firsts[board] += 1;
+            }
+
+            if turn == last_turn {
+                lasts[board] += 1;
+            }
+        }
+    }
+
+    let share = |count: usize| {
+        if games == 0 {
+            0.0
+        } else {
+            count as f64 / games as f64
+        }
+    };
+
+    (0..boards.len())
+        .map(|board| Odds {
+            board,
+            first: share(firsts[board]),
+            last: share(lasts[board]),
+            wins: share(wins[board]),
+            expected_draws: if wins[board] == 0 {
+                None
+            } else {
+                Some(draws[board] as f64 / wins[board] as f64)
+            },
+        })
+        .collect()
+}
+
+/// Slots of the numbers left to mark for each pattern of `board` that can be
+/// completed with the drawn numbers.
+fn board_patterns(board: &Board, rule: &WinRule, slots: &HashMap<u64, usize>) -> Vec<Vec<usize>> {
+    let mut cells: Vec<Option<u64>> = vec![None; board.width() * board.height()];
+
+    for ((x, y), number) in board.unmarked() {
+        cells[y * board.width() + x] = Some(number);
+    }
+
+    rule.patterns(board.width(), board.height())
+        .into_iter()
+        .filter_map(|pattern| {
+            pattern
+                .into_iter()
+                .filter_map(|index| cells[index])
+                .map(|number| slots.get(&number).copied())
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{game::Game, parse_input, tests::EXAMPLE};
+    use super::*;
+
+    #[test]
+    fn seeded_estimates() {
+        let (draw, boards) = parse_input(EXAMPLE).unwrap();
+
+        let odds = estimate(&draw, &boards, &WinRule::lines(), 200, 2021);
+
+        assert_eq!(odds.len(), 3);
+        assert_eq!(odds, estimate(&draw, &boards, &WinRule::lines(), 200, 2021));
+
+        // The draw holds every number of the boards, so they all win.
+        for board in &odds {
+            assert_eq!(board.wins, 1.0);
+            assert!(board.first > 0.0 && board.first < 1.0);
+            assert!(board.last > 0.0 && board.last < 1.0);
+            assert!(board.expected_draws.unwrap() >= 5.0);
+        }
+
+        let firsts: f64 = odds.iter().map(|board| board.first).sum();
+        assert!(firsts >= 1.0);
+    }
+
+    #[test]
+    fn matches_played_games() {
+        let (draw, boards) = parse_input(EXAMPLE).unwrap();
+        let rule = WinRule::Any(vec![WinRule::lines(), WinRule::Diagonals]);
+
+        // A single game shuffles the draw once, like the first one of
+        // `estimate` does.
+        let mut numbers: Vec<u64> = draw.iter().copied().collect();
+        Rng::new(9).shuffle(&mut numbers);
+
+        let shuffled = Draw::from(numbers);
+        let winners = Game::new(&shuffled, boards.clone(), rule.clone()).play_all();
+        let odds = estimate(&draw, &boards, &rule, 1, 9);
+
+        for win in &winners {
+            assert_eq!(odds[win.board].expected_draws, Some(win.turn as f64));
+            assert_eq!(odds[win.board].first == 1.0, win.turn == winners[0].turn);
+            assert_eq!(
+                odds[win.board].last == 1.0,
+                win.turn == winners.last().unwrap().turn
+            );
+        }
+    }
+
+    #[test]
+    fn boards_that_cannot_win() {
+        let draw = Draw::from(vec![1, 2, 3]);
+        let boards: Vec<Board> = vec!["1 2\n3 4\n".parse().unwrap(), "5 6\n7 8\n".parse().unwrap()];
+
+        let odds = estimate(&draw, &boards, &WinRule::lines(), 50, 1);
+
+        assert_eq!(odds[0].wins, 1.0);
+        assert_eq!(odds[0].first, 1.0);
+        assert_eq!(odds[1].wins, 0.0);
+        assert_eq!(odds[1].expected_draws, None);
+        assert!(estimate(&draw, &boards, &WinRule::lines(), 0, 1)
+            .iter()
+            .all(|board| board.wins == 0.0));
+    }
+}
@@ -0,0 +1,187 @@
+//! Checks that a game follows the rules of the puzzle, which parsing alone
+//! does not enforce.
+
+use super::bingo::{Board, Draw};
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+/// Something wrong with a game. Boards and draw positions are 0-based
+/// indexes, cells are given as `(x, y)` coordinates.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Issue {
+    /// `number` is drawn again at `index` after being drawn at `first`.
+    DuplicateInDraw {
+        number: u64,
+        first: usize,
+        index: usize,
+    },
+    /// `number` appears again at `position` after being found at `first`.
+    DuplicateInBoard {
+        board: usize,
+        number: u64,
+        first: (usize, usize),
+        position: (usize, usize),
+    },
+    /// The cell at `position` can never be marked.
+    NeverDrawn {
+        board: usize,
+        number: u64,
+        position: (usize, usize),
+    },
+}
+
+impl Display for Issue {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Self::DuplicateInDraw {
+                number,
+                first,
+                index,
+            } => write!(
+                f,
+                "draw: {} at position {} was already drawn at position {}",
+                number, index, first
+            ),
+            Self::DuplicateInBoard {
+                board,
+                number,
+                first,
+                position,
+            } => write!(
+                f,
+                "board {}: {} at {:?} is already at {:?}",
+                board, number, position, first
+            ),
+            Self::NeverDrawn {
+                board,
+                number,
+                position,
+            } => write!(
+                f,
+                "board {}: {} at {:?} is never drawn",
+                board, number, position
+            ),
+        }
+    }
+}
+
+/// Lists every issue of the game, those of the draw first and then those of
+/// each board in order.
+pub fn validate(draw: &Draw, boards: &[Board]) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    let mut drawn: HashMap<u64, usize> = HashMap::new();
+
+    for (index, number) in draw.iter().enumerate() {
+        match drawn.get(number) {
+            Some(first) => issues.push(Issue::DuplicateInDraw {
+                number: *number,
+                first: *first,
+                index,
+            }),
+            None => {
+                drawn.insert(*number, index);
+            }
+        }
+    }
+
+    for (board_index, board) in boards.iter().enumerate() {
+        let mut seen: HashMap<u64, (usize, usize)> = HashMap::new();
+
+        for (position, number) in board.unmarked() {
+            if let Some(first) = seen.get(&number) {
+                issues.push(Issue::DuplicateInBoard {
+                    board: board_index,
+                    number,
+                    first: *first,
+                    position,
+                });
+                continue;
+            }
+
+            seen.insert(number, position);
+
+            if !drawn.contains_key(&number) {
+                issues.push(Issue::NeverDrawn {
+                    board: board_index,
+                    number,
+                    position,
+                });
+            }
+        }
+    }
+
+    issues
+}
+
+/// 1-based line of the input where `issue` is, assuming the layout of
+/// [`super::parse_input`]: the draw on the first line, then each board after
+/// an empty line.
+pub fn line_of(issue: &Issue, boards: &[Board]) -> usize {
+    let (board, y) = match issue {
+        Issue::DuplicateInDraw { .. } => return 1,
+        Issue::DuplicateInBoard {
+            board, position, ..
+        }
+        | Issue::NeverDrawn {
+            board, position, ..
+        } => (*board, position.1),
+    };
+
+    let first_line = 3 + boards[..board]
+        .iter()
+        .map(|board| board.height() + 1)
+        .sum::<usize>();
+
+    first_line + y
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{parse_input, tests::EXAMPLE};
+    use super::*;
+
+    #[test]
+    fn valid_example() {
+        let (draw, boards) = parse_input(EXAMPLE).unwrap();
+
+        assert_eq!(validate(&draw, &boards), Vec::new());
+    }
+
+    #[test]
+    fn report_issues() {
+        let (draw, boards) = parse_input("1,2,3,2,4\n\n1 2\n3 4\n\n5 1\n1 2\n").unwrap();
+
+        assert_eq!(
+            validate(&draw, &boards),
+            vec![
+                Issue::DuplicateInDraw {
+                    number: 2,
+                    first: 1,
+                    index: 3
+                },
+                Issue::NeverDrawn {
+                    board: 1,
+                    number: 5,
+                    position: (0, 0)
+                },
+                Issue::DuplicateInBoard {
+                    board: 1,
+                    number: 1,
+                    first: (1, 0),
+                    position: (0, 1)
+                },
+            ]
+        );
+        assert_eq!(
+            validate(&draw, &boards)[2].to_string(),
+            "board 1: 1 at (0, 1) is already at (1, 0)"
+        );
+
+        let lines: Vec<usize> = validate(&draw, &boards)
+            .iter()
+            .map(|issue| line_of(issue, &boards))
+            .collect();
+
+        assert_eq!(lines, vec![1, 6, 7]);
+    }
+}
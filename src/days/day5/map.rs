@@ -0,0 +1,143 @@
+//! Point by point view of the vents, to inspect where and why lines overlap.
+
+use super::{Point, Segment};
+use std::collections::HashMap;
+
+/// How many segments cover each point, and which ones.
+///
+/// Segments are identified by their index in the input, which is their line
+/// number minus one.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct VentMap {
+    points: HashMap<Point, Vec<usize>>,
+}
+
+impl VentMap {
+    pub fn new(segments: &[Segment]) -> Self {
+        Self::filtered(segments, |_segment| true)
+    }
+
+    /// Only walks the segments accepted by `filter`, any slope being
+    /// rasterized.
+    pub fn filtered<F>(segments: &[Segment], filter: F) -> Self
+    where
+        F: Fn(&Segment) -> bool,
+    {
+        let mut points: HashMap<Point, Vec<usize>> = HashMap::new();
+
+        for (index, segment) in segments.iter().enumerate() {
+            if !filter(segment) {
+                continue;
+            }
+
+            for point in segment.iter() {
+                points.entry(point).or_default().push(index);
+            }
+        }
+
+        Self { points }
+    }
+
+    /// Number of segments covering `point`.
+    pub fn density(&self, point: Point) -> usize {
+        self.points.get(&point).map_or(0, Vec::len)
+    }
+
+    /// Every covered point along with its density, in no particular order.
+    pub fn densities(&self) -> impl Iterator<Item = (Point, usize)> + '_ {
+        self.points
+            .iter()
+            .map(|(point, segments)| (*point, segments.len()))
+    }
+
+    /// Number of points covered by at least `threshold` segments.
+    pub fn count_at_least(&self, threshold: usize) -> usize {
+        self.densities()
+            .filter(|(_point, density)| *density >= threshold)
+            .count()
+    }
+
+    /// The `n` densest points, ties being ordered by row then column.
+    pub fn most_dangerous(&self, n: usize) -> Vec<(Point, usize)> {
+        let mut densities: Vec<(Point, usize)> = self.densities().collect();
+
+        densities.sort_unstable_by_key(|(point, density)| {
+            (std::cmp::Reverse(*density), point.y, point.x)
+        });
+        densities.truncate(n);
+
+        densities
+    }
+
+    /// Indexes of the segments covering `point`, in input order.
+    pub fn contributors(&self, point: Point) -> &[usize] {
+        self.points.get(&point).map_or(&[], Vec::as_slice)
+    }
+
+    /// Smallest rectangle holding every covered point, as its top left and
+    /// bottom right corners.
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        let mut points = self.points.keys();
+        let first = *points.next()?;
+
+        Some(points.fold((first, first), |(min, max), point| {
+            (
+                Point {
+                    x: min.x.min(point.x),
+                    y: min.y.min(point.y),
+                },
+                Point {
+                    x: max.x.max(point.x),
+                    y: max.y.max(point.y),
+                },
+            )
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{parse, tests::EXAMPLE};
+    use super::*;
+
+    #[test]
+    fn example_densities() {
+        let segments = parse(EXAMPLE).unwrap();
+        let axial = VentMap::filtered(&segments, |segment| segment.is_axial());
+        let map = VentMap::new(&segments);
+
+        assert_eq!(axial.count_at_least(2), 5);
+        assert_eq!(map.count_at_least(2), 12);
+        assert_eq!(map.count_at_least(3), 2);
+        assert_eq!(map.count_at_least(1), 39);
+        assert_eq!(map.density(Point { x: 6, y: 4 }), 3);
+        assert_eq!(map.density(Point { x: 9, y: 9 }), 0);
+        assert_eq!(
+            map.bounds(),
+            Some((Point { x: 0, y: 0 }, Point { x: 9, y: 9 }))
+        );
+    }
+
+    #[test]
+    fn most_dangerous_points() {
+        let map = VentMap::new(&parse(EXAMPLE).unwrap());
+
+        assert_eq!(
+            map.most_dangerous(3),
+            vec![
+                (Point { x: 4, y: 4 }, 3),
+                (Point { x: 6, y: 4 }, 3),
+                (Point { x: 7, y: 1 }, 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn contributing_lines() {
+        let map = VentMap::new(&parse(EXAMPLE).unwrap());
+
+        // 9,4 -> 3,4, 6,4 -> 2,0 and 5,5 -> 8,2.
+        assert_eq!(map.contributors(Point { x: 6, y: 4 }), &[2, 5, 9]);
+        assert!(map.contributors(Point { x: 9, y: 9 }).is_empty());
+    }
+}
@@ -0,0 +1,530 @@
+use crate::error::{self, Error};
+use crate::solution::{Answer, Part, Solution, Variant};
+use std::fmt::{Display, Formatter};
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+pub mod map;
+pub mod render;
+pub mod sweep;
+
+use map::VentMap;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    const INPUT: &'static str = include_str!("input.txt");
+    const ANSWERS: &'static str = include_str!("answers.txt");
+
+    type Input = Vec<Segment>;
+
+    fn parse(input: &str) -> error::Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(segments: &Self::Input) -> error::Result<Answer> {
+        part1(segments).map(Answer::from)
+    }
+
+    fn part2(segments: &Self::Input) -> error::Result<Answer> {
+        part2(segments).map(Answer::from)
+    }
+
+    fn variants() -> Vec<Variant<Self::Input>> {
+        vec![
+            Variant {
+                part: Part::One,
+                name: "raster",
+                solve: |segments| Ok(part1_raster(segments).into()),
+            },
+            Variant {
+                part: Part::Two,
+                name: "raster",
+                solve: |segments| Ok(part2_raster(segments).into()),
+            },
+        ]
+    }
+}
+
+pub fn parse(input: &str) -> error::Result<Vec<Segment>> {
+    error::parse_lines(input)
+}
+
+pub fn part1(segments: &[Segment]) -> error::Result<usize> {
+    sweep::count_overlaps(segments.iter().filter(|segment| segment.is_axial()))
+}
+
+/// The puzzle only has horizontal, vertical and diagonal segments.
+pub fn part2(segments: &[Segment]) -> error::Result<usize> {
+    count_overlaps(segments, Oblique::Error)
+}
+
+/// How to deal with segments that are neither horizontal, vertical nor
+/// diagonal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Oblique {
+    /// Leave them out.
+    Reject,
+    /// Walk them with Bresenham's line algorithm, which is slower as every
+    /// segment then has to be walked point by point.
+    Rasterize,
+    /// Fail on the first of them.
+    Error,
+}
+
+/// Number of points covered by at least two of `segments`.
+pub fn count_overlaps(segments: &[Segment], oblique: Oblique) -> error::Result<usize> {
+    let first_oblique = segments
+        .iter()
+        .position(|segment| segment.orientation() == Orientation::Oblique);
+
+    match (first_oblique, oblique) {
+        (None, _) => sweep::count_overlaps(segments),
+        (Some(_), Oblique::Reject) => sweep::count_overlaps(
+            segments
+                .iter()
+                .filter(|segment| segment.orientation() != Orientation::Oblique),
+        ),
+        (Some(_), Oblique::Rasterize) => Ok(VentMap::new(segments).count_at_least(2)),
+        (Some(index), Oblique::Error) => Err(Error::new(format!(
+            "segment {} is neither horizontal, vertical nor diagonal",
+            segments[index]
+        ))
+        .at_line(index + 1)),
+    }
+}
+
+pub fn part1_raster(segments: &[Segment]) -> usize {
+    VentMap::filtered(segments, |segment| segment.is_axial()).count_at_least(2)
+}
+
+pub fn part2_raster(segments: &[Segment]) -> usize {
+    VentMap::new(segments).count_at_least(2)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Segment(Point, Point);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Orientation {
+    /// Segments of a single point are horizontal.
+    Horizontal,
+    Vertical,
+    /// At 45°, `x - y` being constant.
+    Diagonal,
+    /// At 45°, `x + y` being constant.
+    AntiDiagonal,
+    /// Any other slope.
+    Oblique,
+}
+
+impl Segment {
+    pub fn orientation(&self) -> Orientation {
+        let Segment(a, b) = self;
+        // Sums and differences of coordinates may not fit an i64.
+        let (ax, ay, bx, by) = (a.x as i128, a.y as i128, b.x as i128, b.y as i128);
+
+        if a.y == b.y {
+            Orientation::Horizontal
+        } else if a.x == b.x {
+            Orientation::Vertical
+        } else if ax - ay == bx - by {
+            Orientation::Diagonal
+        } else if ax + ay == bx + by {
+            Orientation::AntiDiagonal
+        } else {
+            Orientation::Oblique
+        }
+    }
+
+    pub fn is_axial(&self) -> bool {
+        matches!(
+            self.orientation(),
+            Orientation::Horizontal | Orientation::Vertical
+        )
+    }
+
+    /// Walks the points of the segment from its first end to its second one.
+    fn iter(&self) -> SegmentIter {
+        SegmentIter::new(self)
+    }
+}
+
+/// Bresenham's line algorithm, which walks horizontal, vertical and
+/// diagonal segments one step at a time along each axis and approximates
+/// other slopes with the closest points.
+///
+/// Lengths and errors are kept on 128 bits, as coordinates may span the whole
+/// range of i64.
+#[derive(Debug)]
+struct SegmentIter {
+    current: Option<Point>,
+    end: Point,
+    dx: i128,
+    dy: i128,
+    step_x: i64,
+    step_y: i64,
+    error: i128,
+}
+
+impl SegmentIter {
+    fn new(segment: &Segment) -> Self {
+        let Segment(start, end) = *segment;
+        let dx = (end.x as i128 - start.x as i128).abs();
+        let dy = -(end.y as i128 - start.y as i128).abs();
+
+        SegmentIter {
+            current: Some(start),
+            end,
+            dx,
+            dy,
+            step_x: end.x.cmp(&start.x) as i64,
+            step_y: end.y.cmp(&start.y) as i64,
+            error: dx + dy,
+        }
+    }
+}
+
+impl Iterator for SegmentIter {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current?;
+
+        if current == self.end {
+            self.current = None;
+
+            return Some(current);
+        }
+
+        let mut next = current;
+        let double_error = 2 * self.error;
+
+        if double_error >= self.dy {
+            self.error += self.dy;
+            next.x += self.step_x;
+        }
+
+        if double_error <= self.dx {
+            self.error += self.dx;
+            next.y += self.step_y;
+        }
+
+        self.current = Some(next);
+
+        Some(current)
+    }
+}
+
+impl Display for Segment {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{} -> {}", self.0, self.1)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParseSegmentError {
+    InvalidFormat,
+    InvalidPoint(ParsePointError),
+}
+
+impl Display for ParseSegmentError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Self::InvalidFormat => write!(f, "expected a segment like `x1,y1 -> x2,y2`"),
+            Self::InvalidPoint(err) => write!(f, "invalid point ({})", err),
+        }
+    }
+}
+
+impl From<ParsePointError> for ParseSegmentError {
+    fn from(err: ParsePointError) -> Self {
+        ParseSegmentError::InvalidPoint(err)
+    }
+}
+
+impl FromStr for Segment {
+    type Err = ParseSegmentError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split(" -> ");
+
+        let point_a: Point = parts
+            .next()
+            .ok_or(ParseSegmentError::InvalidFormat)?
+            .parse()?;
+
+        let point_b: Point = parts
+            .next()
+            .ok_or(ParseSegmentError::InvalidFormat)?
+            .parse()?;
+
+        if parts.next().is_some() {
+            Err(ParseSegmentError::InvalidFormat)
+        } else {
+            Ok(Segment(point_a, point_b))
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParsePointError {
+    InvalidFormat,
+    InvalidNumber(ParseIntError),
+}
+
+impl Display for ParsePointError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Self::InvalidFormat => write!(f, "expected a point like `x,y`"),
+            Self::InvalidNumber(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl From<ParseIntError> for ParsePointError {
+    fn from(err: ParseIntError) -> Self {
+        ParsePointError::InvalidNumber(err)
+    }
+}
+
+impl FromStr for Point {
+    type Err = ParsePointError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split(',');
+
+        let x = parts
+            .next()
+            .ok_or(ParsePointError::InvalidFormat)?
+            .parse()?;
+
+        let y = parts
+            .next()
+            .ok_or(ParsePointError::InvalidFormat)?
+            .parse()?;
+
+        if parts.next().is_some() {
+            Err(ParsePointError::InvalidFormat)
+        } else {
+            Ok(Point { x, y })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    pub(super) const EXAMPLE: &str = "\
+0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2
+";
+
+    #[test]
+    fn part1_example() {
+        let segments = parse(EXAMPLE).unwrap();
+
+        assert_eq!(part1(&segments), Ok(5));
+        assert_eq!(part1_raster(&segments), 5);
+    }
+
+    #[test]
+    fn part2_example() {
+        let segments = parse(EXAMPLE).unwrap();
+
+        assert_eq!(part2(&segments), Ok(12));
+        assert_eq!(part2_raster(&segments), 12);
+    }
+
+    #[test]
+    fn parse_point() {
+        assert_eq!("3,4".parse(), Ok(Point { x: 3, y: 4 }));
+
+        assert!(matches!(
+            "1,2,3".parse::<Point>(),
+            Err(ParsePointError::InvalidFormat),
+        ));
+        assert!(matches!(
+            "one,2".parse::<Point>(),
+            Err(ParsePointError::InvalidNumber(_)),
+        ));
+    }
+
+    #[test]
+    fn parse_segment() {
+        assert_eq!(
+            "0,9 -> 5,9".parse::<Segment>().unwrap().to_string(),
+            "0,9 -> 5,9"
+        );
+        assert_eq!(
+            "0,9 -> 5,9".parse(),
+            Ok(Segment(Point { x: 0, y: 9 }, Point { x: 5, y: 9 })),
+        );
+
+        assert!(matches!(
+            "0,9 -> 5,8".parse::<Segment>(),
+            Ok(Segment(Point { x: 0, y: 9 }, Point { x: 5, y: 8 })),
+        ));
+
+        assert!(matches!(
+            "0,9 -> 5,9 -> 0,0".parse::<Segment>(),
+            Err(ParseSegmentError::InvalidFormat),
+        ));
+
+        assert!(matches!(
+            "0,9 -> 5;9".parse::<Segment>(),
+            Err(ParseSegmentError::InvalidPoint(_)),
+        ));
+    }
+
+    #[test]
+    fn segment_iter_x() {
+        let segment = Segment(Point { x: 0, y: 9 }, Point { x: 2, y: 9 });
+        let mut it = segment.iter();
+
+        assert_eq!(it.next(), Some(Point { x: 0, y: 9 }));
+        assert_eq!(it.next(), Some(Point { x: 1, y: 9 }));
+        assert_eq!(it.next(), Some(Point { x: 2, y: 9 }));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next(), None);
+
+        assert_eq!(segment.iter().count(), 3);
+    }
+
+    #[test]
+    fn segment_iter_y() {
+        let segment = Segment(Point { x: 2, y: 9 }, Point { x: 2, y: 5 });
+        let mut it = segment.iter();
+
+        assert_eq!(it.next(), Some(Point { x: 2, y: 9 }));
+        assert_eq!(it.next(), Some(Point { x: 2, y: 8 }));
+        assert_eq!(it.next(), Some(Point { x: 2, y: 7 }));
+        assert_eq!(it.next(), Some(Point { x: 2, y: 6 }));
+        assert_eq!(it.next(), Some(Point { x: 2, y: 5 }));
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next(), None);
+
+        assert_eq!(segment.iter().count(), 5);
+    }
+
+    #[test]
+    fn segment_iter_diagonal() {
+        let segment = Segment(Point { x: 8, y: 0 }, Point { x: 5, y: 3 });
+
+        assert_eq!(
+            segment.iter().collect::<Vec<_>>(),
+            vec![
+                Point { x: 8, y: 0 },
+                Point { x: 7, y: 1 },
+                Point { x: 6, y: 2 },
+                Point { x: 5, y: 3 },
+            ]
+        );
+    }
+
+    #[test]
+    fn segment_iter_oblique() {
+        let segment = Segment(Point { x: 0, y: 0 }, Point { x: 4, y: 2 });
+
+        assert_eq!(
+            segment.iter().collect::<Vec<_>>(),
+            vec![
+                Point { x: 0, y: 0 },
+                Point { x: 1, y: 1 },
+                Point { x: 2, y: 1 },
+                Point { x: 3, y: 2 },
+                Point { x: 4, y: 2 },
+            ]
+        );
+
+        let reversed = Segment(Point { x: 1, y: 5 }, Point { x: 0, y: 0 });
+        let points: Vec<_> = reversed.iter().collect();
+
+        assert_eq!(points.len(), 6);
+        assert_eq!(points.last(), Some(&Point { x: 0, y: 0 }));
+    }
+
+    #[test]
+    fn orientations() {
+        let orientation = |s: &str| s.parse::<Segment>().unwrap().orientation();
+
+        assert_eq!(orientation("0,9 -> 5,9"), Orientation::Horizontal);
+        assert_eq!(orientation("3,3 -> 3,3"), Orientation::Horizontal);
+        assert_eq!(orientation("7,0 -> 7,4"), Orientation::Vertical);
+        assert_eq!(orientation("0,0 -> 8,8"), Orientation::Diagonal);
+        assert_eq!(orientation("8,0 -> 0,8"), Orientation::AntiDiagonal);
+        assert_eq!(orientation("0,0 -> 4,2"), Orientation::Oblique);
+        assert_eq!(
+            orientation("-9223372036854775808,5 -> -9223372036854775807,6"),
+            Orientation::Diagonal
+        );
+        assert_eq!(
+            orientation("9223372036854775807,5 -> 9223372036854775806,6"),
+            Orientation::AntiDiagonal
+        );
+        assert_eq!(
+            orientation("-9223372036854775808,0 -> 9223372036854775807,1"),
+            Orientation::Oblique
+        );
+    }
+
+    #[test]
+    fn segment_iter_extreme() {
+        let segment: Segment = "-9223372036854775808,5 -> -9223372036854775807,6"
+            .parse()
+            .unwrap();
+
+        assert_eq!(segment.iter().count(), 2);
+
+        let segment: Segment =
+            "9223372036854775805,-9223372036854775808 -> 9223372036854775807,9223372036854775807"
+                .parse()
+                .unwrap();
+        let mut points = segment.iter();
+
+        assert_eq!(
+            points.next(),
+            Some(Point {
+                x: i64::MAX - 2,
+                y: i64::MIN
+            })
+        );
+        assert_eq!(points.next().map(|point| point.x), Some(i64::MAX - 2));
+    }
+
+    #[test]
+    fn oblique_segments() {
+        let segments = parse("0,0 -> 4,0\n0,0 -> 4,2\n2,0 -> 2,3\n").unwrap();
+
+        assert_eq!(count_overlaps(&segments, Oblique::Reject), Ok(1));
+        assert_eq!(count_overlaps(&segments, Oblique::Rasterize), Ok(3));
+        assert_eq!(
+            count_overlaps(&segments, Oblique::Error)
+                .unwrap_err()
+                .line(),
+            Some(2)
+        );
+    }
+}
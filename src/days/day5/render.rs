@@ -0,0 +1,425 @@
+//! Pictures of a [`VentMap`], as ASCII diagrams like the ones of the puzzle
+//! or as heatmaps.
+
+use super::map::VentMap;
+use super::Point;
+use crate::grid::Grid;
+use std::fmt::{Display, Formatter};
+use std::io::{self, Write};
+
+/// Largest number of points or pixels a picture may have, past which maps
+/// must be shrunk.
+pub const MAX_PIXELS: u64 = 1 << 24;
+
+#[derive(Debug)]
+pub enum RenderError {
+    /// The picture would be `width` by `height`, more than [`MAX_PIXELS`].
+    TooLarge {
+        width: u128,
+        height: u128,
+    },
+    Io(io::Error),
+}
+
+impl Display for RenderError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Self::TooLarge { width, height } => write!(
+                f,
+                "the picture would be {}x{}, more than {} pixels, try --shrink",
+                width, height, MAX_PIXELS
+            ),
+            Self::Io(err) => write!(f, "could not write the picture: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for RenderError {}
+
+impl From<io::Error> for RenderError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// Renders the map as in the puzzle, `.` standing for uncovered points and
+/// densities above 9 being shown as `+`. The diagram starts at 0,0, or
+/// further up or left when some points have negative coordinates.
+pub fn ascii(map: &VentMap) -> Result<String, RenderError> {
+    let densities = match densities(map, Scale::Enlarge(1), true)? {
+        Some(densities) => densities,
+        None => return Ok(String::new()),
+    };
+
+    let mut diagram = String::with_capacity((densities.width() + 1) * densities.height());
+
+    for row in densities.rows() {
+        for density in row {
+            diagram.push(match density {
+                0 => '.',
+                1..=9 => char::from_digit(*density as u32, 10).expect("single digit"),
+                _ => '+',
+            });
+        }
+
+        diagram.push('\n');
+    }
+
+    Ok(diagram)
+}
+
+/// How points map to pixels.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Scale {
+    /// Each point is drawn as a square of this many pixels wide.
+    Enlarge(usize),
+    /// Each pixel shows the densest point of a square of this many points
+    /// wide, for maps too large to be drawn point by point.
+    Shrink(usize),
+}
+
+impl Default for Scale {
+    fn default() -> Self {
+        Self::Enlarge(1)
+    }
+}
+
+/// How densities map to the brightness of pixels.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Intensity {
+    #[default]
+    Linear,
+    /// Keeps low densities visible next to a few very dense points.
+    Logarithmic,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Heatmap {
+    pub scale: Scale,
+    pub intensity: Intensity,
+}
+
+impl Heatmap {
+    /// Writes a binary grayscale PGM image, white pixels being the densest.
+    pub fn write_pgm<W: Write>(&self, mut w: W, map: &VentMap) -> Result<(), RenderError> {
+        let levels = self.levels(map)?;
+
+        write!(w, "P5\n{} {}\n255\n", levels.width(), levels.height())?;
+        w.write_all(levels.cells())?;
+
+        Ok(())
+    }
+
+    /// Writes a binary PPM image, from black for uncovered points to white
+    /// through red and yellow.
+    pub fn write_ppm<W: Write>(&self, mut w: W, map: &VentMap) -> Result<(), RenderError> {
+        let levels = self.levels(map)?;
+
+        write!(w, "P6\n{} {}\n255\n", levels.width(), levels.height())?;
+
+        for level in levels.cells() {
+            w.write_all(&color(*level))?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes an SVG image with one square per covered pixel.
+    pub fn write_svg<W: Write>(&self, mut w: W, map: &VentMap) -> Result<(), RenderError> {
+        let levels = self.levels(map)?;
+
+        writeln!(
+            w,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{1}" viewBox="0 0 {0} {1}" shape-rendering="crispEdges">"#,
+            levels.width(),
+            levels.height()
+        )?;
+        writeln!(w, r#"<rect width="100%" height="100%" fill="black"/>"#)?;
+
+        for (index, level) in levels.cells().iter().enumerate() {
+            if *level == 0 {
+                continue;
+            }
+
+            let (x, y) = levels.coords_of(index).expect("cell of the grid");
+            let [r, g, b] = color(*level);
+
+            writeln!(
+                w,
+                "<rect x=\"{}\" y=\"{}\" width=\"1\" height=\"1\" fill=\"#{:02x}{:02x}{:02x}\"/>",
+                x, y, r, g, b
+            )?;
+        }
+
+        writeln!(w, "</svg>")?;
+
+        Ok(())
+    }
+
+    /// Brightness of each pixel, from 0 for uncovered points to 255 for the
+    /// densest ones.
+    fn levels(&self, map: &VentMap) -> Result<Grid<u8>, RenderError> {
+        let densities = match densities(map, self.scale, false)? {
+            Some(densities) => densities,
+            None => return Ok(Grid::filled(0, 0, 0)),
+        };
+
+        let max = densities.cells().iter().copied().max().unwrap_or_default();
+        let level = |density: usize| -> u8 {
+            let ratio = match self.intensity {
+                _ if density == 0 => 0.0,
+                Intensity::Linear => density as f64 / max as f64,
+                Intensity::Logarithmic => (density as f64).ln_1p() / (max as f64).ln_1p(),
+            };
+
+            (ratio * 255.0).round() as u8
+        };
+
+        let pixel_size = match self.scale {
+            Scale::Enlarge(size) => size.max(1),
+            Scale::Shrink(_) => 1,
+        };
+
+        let (width, height) = (
+            densities.width() as u128 * pixel_size as u128,
+            densities.height() as u128 * pixel_size as u128,
+        );
+        check_size(width, height)?;
+
+        let mut levels = Grid::filled(width as usize, height as usize, 0);
+
+        for (index, density) in densities.cells().iter().enumerate() {
+            let (x, y) = densities.coords_of(index).expect("cell of the grid");
+
+            for dy in 0..pixel_size {
+                for dx in 0..pixel_size {
+                    levels[(x * pixel_size + dx, y * pixel_size + dy)] = level(*density);
+                }
+            }
+        }
+
+        Ok(levels)
+    }
+}
+
+/// Black to red, then yellow, then white.
+fn color(level: u8) -> [u8; 3] {
+    let level = level as u16 * 3;
+
+    [
+        level.min(255) as u8,
+        level.saturating_sub(255).min(255) as u8,
+        level.saturating_sub(510).min(255) as u8,
+    ]
+}
+
+/// Densities over the bounds of `map`, one cell per point or per square of
+/// points when shrinking. With `from_origin`, the bounds are extended up and
+/// left to 0,0.
+fn densities(
+    map: &VentMap,
+    scale: Scale,
+    from_origin: bool,
+) -> Result<Option<Grid<usize>>, RenderError> {
+    let (mut min, max) = match map.bounds() {
+        Some(bounds) => bounds,
+        None => return Ok(None),
+    };
+
+    if from_origin {
+        min = Point {
+            x: min.x.min(0),
+            y: min.y.min(0),
+        };
+    }
+
+    let block = match scale {
+        Scale::Enlarge(_) => 1,
+        Scale::Shrink(size) => size.max(1) as i128,
+    };
+
+    // Kept on 128 bits, as coordinates may span the whole range of i64.
+    let width = ((max.x as i128 - min.x as i128) / block + 1) as u128;
+    let height = ((max.y as i128 - min.y as i128) / block + 1) as u128;
+    check_size(width, height)?;
+
+    let mut densities = Grid::filled(width as usize, height as usize, 0);
+
+    for (Point { x, y }, density) in map.densities() {
+        let cell = &mut densities[(
+            ((x as i128 - min.x as i128) / block) as usize,
+            ((y as i128 - min.y as i128) / block) as usize,
+        )];
+
+        *cell = density.max(*cell);
+    }
+
+    Ok(Some(densities))
+}
+
+fn check_size(width: u128, height: u128) -> Result<(), RenderError> {
+    match width.checked_mul(height) {
+        Some(pixels) if pixels <= MAX_PIXELS as u128 => Ok(()),
+        _ => Err(RenderError::TooLarge { width, height }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{parse, tests::EXAMPLE};
+    use super::*;
+
+    #[test]
+    fn ascii_example() {
+        let map = VentMap::new(&parse(EXAMPLE).unwrap());
+
+        assert_eq!(
+            ascii(&map).unwrap(),
+            "\
+1.1....11.
+.111...2..
+..2.1.111.
+...1.2.2..
+.112313211
+...1.2....
+..1...1...
+.1.....1..
+1.......1.
+222111....
+"
+        );
+
+        let axial = VentMap::filtered(&parse(EXAMPLE).unwrap(), |segment| segment.is_axial());
+
+        assert!(ascii(&axial)
+            .unwrap()
+            .starts_with(".......1..\n..1....1..\n"));
+        assert_eq!(ascii(&VentMap::default()).unwrap(), "");
+    }
+
+    #[test]
+    fn ascii_from_origin() {
+        let map = VentMap::new(&parse("2,2 -> 3,2\n3,1 -> 3,3\n").unwrap());
+
+        assert_eq!(ascii(&map).unwrap(), "....\n...1\n..12\n...1\n");
+
+        let map = VentMap::new(&parse("-1,0 -> 0,0\n").unwrap());
+
+        assert_eq!(ascii(&map).unwrap(), "11\n");
+    }
+
+    #[test]
+    fn too_large() {
+        let map = VentMap::new(&parse("0,0 -> 5000,0\n0,0 -> 0,5000\n").unwrap());
+
+        assert!(matches!(
+            Heatmap::default().write_pgm(Vec::new(), &map),
+            Err(RenderError::TooLarge {
+                width: 5001,
+                height: 5001
+            })
+        ));
+        assert!(ascii(&map).is_err());
+        assert!(Heatmap {
+            scale: Scale::Shrink(5000),
+            intensity: Intensity::Linear,
+        }
+        .write_pgm(Vec::new(), &map)
+        .is_ok());
+
+        let small = VentMap::new(&parse("0,0 -> 4095,4095\n").unwrap());
+        let enlarged = Heatmap {
+            scale: Scale::Enlarge(2),
+            intensity: Intensity::Linear,
+        };
+
+        assert!(enlarged.write_pgm(Vec::new(), &small).is_err());
+
+        let single = VentMap::new(&parse("0,0 -> 0,0\n").unwrap());
+        let huge = Heatmap {
+            scale: Scale::Enlarge(usize::MAX),
+            intensity: Intensity::Linear,
+        };
+
+        assert!(matches!(
+            huge.write_pgm(Vec::new(), &single),
+            Err(RenderError::TooLarge { width, height })
+                if width == usize::MAX as u128 && height == usize::MAX as u128
+        ));
+    }
+
+    #[test]
+    fn whole_i64_range() {
+        let map = VentMap::new(
+            &parse(
+                "\
+-9223372036854775808,0 -> -9223372036854775808,0
+9223372036854775807,0 -> 9223372036854775807,0
+",
+            )
+            .unwrap(),
+        );
+
+        assert!(matches!(
+            ascii(&map),
+            Err(RenderError::TooLarge { width, height: 1 }) if width == 1 << 64
+        ));
+        assert!(Heatmap::default().write_pgm(Vec::new(), &map).is_err());
+    }
+
+    #[test]
+    fn pgm_heatmap() {
+        let map = VentMap::new(&parse("0,0 -> 2,0\n1,0 -> 1,0\n").unwrap());
+        let mut pgm = Vec::new();
+
+        Heatmap::default().write_pgm(&mut pgm, &map).unwrap();
+
+        assert_eq!(pgm, b"P5\n3 1\n255\n\x80\xff\x80");
+
+        let mut pgm = Vec::new();
+
+        Heatmap {
+            scale: Scale::Enlarge(2),
+            intensity: Intensity::Linear,
+        }
+        .write_pgm(&mut pgm, &map)
+        .unwrap();
+
+        assert!(pgm.starts_with(b"P5\n6 2\n255\n\x80\x80\xff\xff"));
+        assert_eq!(pgm.len(), b"P5\n6 2\n255\n".len() + 12);
+    }
+
+    #[test]
+    fn shrunk_heatmap() {
+        let map = VentMap::new(&parse("0,0 -> 0,999\n0,500 -> 999,500\n").unwrap());
+        let mut ppm = Vec::new();
+
+        Heatmap {
+            scale: Scale::Shrink(100),
+            intensity: Intensity::Logarithmic,
+        }
+        .write_ppm(&mut ppm, &map)
+        .unwrap();
+
+        let header = b"P6\n10 10\n255\n";
+
+        assert!(ppm.starts_with(header));
+        assert_eq!(ppm.len(), header.len() + 10 * 10 * 3);
+        // The crossing at 0,500 is the densest point, hence white.
+        assert_eq!(&ppm[header.len() + 50 * 3..][..3], &[255, 255, 255]);
+    }
+
+    #[test]
+    fn svg_heatmap() {
+        let map = VentMap::new(&parse("0,0 -> 1,0\n").unwrap());
+        let mut svg = Vec::new();
+
+        Heatmap::default().write_svg(&mut svg, &map).unwrap();
+
+        let svg = String::from_utf8(svg).unwrap();
+
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.contains(r##"<rect x="1" y="0" width="1" height="1" fill="#ffffff"/>"##));
+        assert!(svg.trim_end().ends_with("</svg>"));
+    }
+}
@@ -0,0 +1,330 @@
+//! Counts overlapping points from the geometry of the segments, without
+//! walking them point by point, so that the cost does not depend on their
+//! length.
+//!
+//! Segments are grouped by line. Sweeping the segments of each line gives the
+//! ranges covered at least twice by collinear segments. Segments of different
+//! directions meet at most once: every pair of segments is checked for a
+//! crossing, and the crossings are kept in a set to be deduplicated against
+//! those ranges. This takes `O(n²)` time, and as much memory when most
+//! segments cross, for `n` segments.
+//!
+//! Positions along lines are computed on 128 bits, as coordinates may span
+//! the whole range of i64.
+
+use super::{Orientation, Point, Segment};
+use crate::error::{self, Error};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum Direction {
+    Horizontal,
+    Vertical,
+    /// `x - y` is constant.
+    Diagonal,
+    /// `x + y` is constant.
+    AntiDiagonal,
+}
+
+const DIRECTIONS: [Direction; 4] = [
+    Direction::Horizontal,
+    Direction::Vertical,
+    Direction::Diagonal,
+    Direction::AntiDiagonal,
+];
+
+impl Direction {
+    /// Coefficients `(a, b)` of the equation `a * x + b * y = key` of the
+    /// lines of this direction.
+    fn coefficients(self) -> (i128, i128) {
+        match self {
+            Self::Horizontal => (0, 1),
+            Self::Vertical => (1, 0),
+            Self::Diagonal => (1, -1),
+            Self::AntiDiagonal => (1, 1),
+        }
+    }
+
+    /// Line of this direction going through `x,y`, and position of the
+    /// point along it.
+    fn locate(self, x: i128, y: i128) -> (i128, i128) {
+        let (a, b) = self.coefficients();
+        let position = match self {
+            Self::Vertical => y,
+            _ => x,
+        };
+
+        (a * x + b * y, position)
+    }
+}
+
+/// A segment seen as the range `start..=end` of positions along a line.
+#[derive(Clone, Copy, Debug)]
+struct Span {
+    direction: Direction,
+    key: i128,
+    start: i128,
+    end: i128,
+}
+
+impl Span {
+    fn new(segment: &Segment) -> error::Result<Self> {
+        let Segment(a, b) = segment;
+
+        let direction = match segment.orientation() {
+            Orientation::Horizontal => Direction::Horizontal,
+            Orientation::Vertical => Direction::Vertical,
+            Orientation::Diagonal => Direction::Diagonal,
+            Orientation::AntiDiagonal => Direction::AntiDiagonal,
+            Orientation::Oblique => {
+                return Err(Error::new(format!(
+                    "segment {} is neither horizontal, vertical nor diagonal",
+                    segment
+                )))
+            }
+        };
+
+        let (key, a_position) = direction.locate(a.x as i128, a.y as i128);
+        let (_, b_position) = direction.locate(b.x as i128, b.y as i128);
+
+        Ok(Self {
+            direction,
+            key,
+            start: a_position.min(b_position),
+            end: a_position.max(b_position),
+        })
+    }
+
+    fn contains(&self, x: i128, y: i128) -> bool {
+        let (key, position) = self.direction.locate(x, y);
+
+        key == self.key && (self.start..=self.end).contains(&position)
+    }
+
+    /// Integer point where both spans meet, when they are not parallel.
+    fn intersection(&self, other: &Span) -> Option<Point> {
+        let (a1, b1) = self.direction.coefficients();
+        let (a2, b2) = other.direction.coefficients();
+        let det = a1 * b2 - a2 * b1;
+
+        if det == 0 {
+            return None;
+        }
+
+        let x = self.key * b2 - other.key * b1;
+        let y = a1 * other.key - a2 * self.key;
+
+        if x % det != 0 || y % det != 0 {
+            return None;
+        }
+
+        let (x, y) = (x / det, y / det);
+
+        // Points of both spans are points of the segments, within i64.
+        (self.contains(x, y) && other.contains(x, y)).then_some(Point {
+            x: x as i64,
+            y: y as i64,
+        })
+    }
+}
+
+/// Number of points covered by at least two of `segments`, which must all be
+/// horizontal, vertical or diagonal.
+pub fn count_overlaps<'a, I>(segments: I) -> error::Result<usize>
+where
+    I: IntoIterator<Item = &'a Segment>,
+{
+    let spans = segments
+        .into_iter()
+        .map(Span::new)
+        .collect::<error::Result<Vec<Span>>>()?;
+
+    let mut lines: HashMap<(Direction, i128), Vec<(i128, i128)>> = HashMap::new();
+
+    for span in &spans {
+        lines
+            .entry((span.direction, span.key))
+            .or_default()
+            .push((span.start, span.end));
+    }
+
+    let overlaps: HashMap<(Direction, i128), Vec<(i128, i128)>> = lines
+        .into_iter()
+        .map(|(line, ranges)| (line, overlapping_ranges(&ranges)))
+        .filter(|(_, ranges)| !ranges.is_empty())
+        .collect();
+
+    let mut count: i128 = overlaps
+        .values()
+        .flatten()
+        .map(|(start, end)| end - start + 1)
+        .sum();
+
+    let crossings: HashSet<Point> = spans
+        .iter()
+        .enumerate()
+        .flat_map(|(i, span)| {
+            spans[i + 1..]
+                .iter()
+                .filter_map(move |other| span.intersection(other))
+        })
+        .collect();
+
+    for point in crossings {
+        let covering_lines = DIRECTIONS
+            .iter()
+            .filter(|direction| {
+                let (key, position) = direction.locate(point.x as i128, point.y as i128);
+
+                overlaps
+                    .get(&(**direction, key))
+                    .is_some_and(|ranges| in_ranges(ranges, position))
+            })
+            .count() as i128;
+
+        // Points already counted on several lines must be counted once, and
+        // crossings out of any overlapping range were not counted yet.
+        count += if covering_lines == 0 {
+            1
+        } else {
+            1 - covering_lines
+        };
+    }
+
+    usize::try_from(count)
+        .map_err(|_| Error::new(format!("more than {} overlapping points", usize::MAX)))
+}
+
+/// Sorted and disjoint ranges of positions covered by at least two of
+/// `ranges`, all of them being inclusive.
+fn overlapping_ranges(ranges: &[(i128, i128)]) -> Vec<(i128, i128)> {
+    let mut events: Vec<(i128, i64)> = ranges
+        .iter()
+        .flat_map(|(start, end)| [(*start, 1), (end + 1, -1)])
+        .collect();
+
+    events.sort_unstable();
+
+    let mut overlaps: Vec<(i128, i128)> = Vec::new();
+    let mut depth = 0;
+    let mut overlap_start = None;
+
+    for (position, delta) in events {
+        depth += delta;
+
+        match overlap_start {
+            None if depth >= 2 => overlap_start = Some(position),
+            Some(start) if depth < 2 => {
+                if start < position {
+                    overlaps.push((start, position - 1));
+                }
+
+                overlap_start = None;
+            }
+            _ => (),
+        }
+    }
+
+    overlaps
+}
+
+fn in_ranges(ranges: &[(i128, i128)], position: i128) -> bool {
+    let after = ranges.partition_point(|(start, _)| *start <= position);
+
+    after > 0 && ranges[after - 1].1 >= position
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{map::VentMap, parse, tests::EXAMPLE};
+    use super::*;
+    use crate::rng::Rng;
+
+    #[test]
+    fn example() {
+        let segments = parse(EXAMPLE).unwrap();
+
+        assert_eq!(
+            count_overlaps(segments.iter().filter(|segment| segment.is_axial())),
+            Ok(5)
+        );
+        assert_eq!(count_overlaps(&segments), Ok(12));
+    }
+
+    #[test]
+    fn ranges_covered_twice() {
+        assert_eq!(
+            overlapping_ranges(&[(0, 5), (3, 8), (8, 9)]),
+            vec![(3, 5), (8, 8)]
+        );
+        assert_eq!(overlapping_ranges(&[(0, 2), (3, 4)]), vec![]);
+        assert_eq!(overlapping_ranges(&[(0, 9), (2, 3), (3, 4)]), vec![(2, 4)]);
+    }
+
+    #[test]
+    fn long_segments() {
+        let segments = parse("0,0 -> 5000000,0\n1000000,0 -> 9000000,0\n3,-5 -> 3,5\n").unwrap();
+
+        assert_eq!(count_overlaps(&segments), Ok(4000002));
+    }
+
+    #[test]
+    fn extreme_coordinates() {
+        let segments = parse(
+            "\
+-9223372036854775808,0 -> 9223372036854775807,0
+9223372036854775807,0 -> 9223372036854775807,0
+-9223372036854775808,-9223372036854775808 -> 9223372036854775807,9223372036854775807
+-9223372036854775808,9223372036854775807 -> 9223372036854775807,-9223372036854775808
+",
+        )
+        .unwrap();
+
+        // The end of the horizontal line and its crossings with the
+        // diagonals, which do not meet on an integer point.
+        assert_eq!(count_overlaps(&segments), Ok(3));
+        assert!(count_overlaps(&[segments[0], segments[0]]).is_err());
+    }
+
+    #[test]
+    fn matches_rasterization() {
+        let mut rng = Rng::new(5);
+
+        for _ in 0..50 {
+            let segments: Vec<Segment> = (0..30)
+                .map(|_| {
+                    let mut coord = || rng.below(20) as i64;
+                    let a = Point {
+                        x: coord(),
+                        y: coord(),
+                    };
+                    let length = coord();
+                    let (dx, dy) =
+                        [(1, 0), (0, 1), (1, 1), (1, -1), (-1, 0), (-1, 1)][rng.below(6) as usize];
+
+                    Segment(
+                        a,
+                        Point {
+                            x: a.x + dx * length,
+                            y: a.y + dy * length,
+                        },
+                    )
+                })
+                .collect();
+
+            assert_eq!(
+                count_overlaps(&segments),
+                Ok(VentMap::new(&segments).count_at_least(2)),
+                "{:?}",
+                segments
+            );
+        }
+    }
+
+    #[test]
+    fn unsupported_slope() {
+        let segments = parse("0,0 -> 2,1\n").unwrap();
+
+        assert!(count_overlaps(&segments).is_err());
+    }
+}
@@ -0,0 +1,460 @@
+//! Lifecycles other than the one of the puzzle: how long fishes take to give
+//! birth, whether they die, and which species their newborns belong to.
+//!
+//! A lifecycle is described by one line per species:
+//!
+//! ```text
+//! lanternfish: reset 6, newborn 8
+//! mayfly: reset 1, newborn 2, lifespan 30, offspring lanternfish
+//! ```
+//!
+//! `reset` is the timer of a fish right after it gave birth and `newborn` the
+//! timer of its newborns. Fishes with a `lifespan` die at the end of their
+//! last day, after giving birth if they were due to. Newborns belong to the
+//! `offspring` species, the species of their parent by default. Blank lines
+//! and lines starting with `#` are ignored.
+//!
+//! Fishes are grouped into cohorts sharing a species, a timer and an age, a
+//! lifecycle telling what becomes of each cohort after a day. A species has
+//! `max(reset, newborn) + 1` cohorts per day of its lifespan, and a lifecycle
+//! at most [`MAX_COHORTS`] cohorts overall.
+
+use super::{NEWBORN_TERM, RESET_TERM};
+use crate::error::{self, Error};
+
+/// Most cohorts a lifecycle may have, keeping populations and the transition
+/// matrices over them small.
+pub const MAX_COHORTS: usize = 1024;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Species {
+    pub name: String,
+    pub reset: u32,
+    pub newborn: u32,
+    /// Days a fish lives, counted from its birth or from the start of the
+    /// simulation for the initial fishes.
+    pub lifespan: Option<u32>,
+    /// Index of the species of the newborns.
+    pub offspring: usize,
+}
+
+impl Species {
+    /// Longest timer a fish of this species can have.
+    pub fn max_timer(&self) -> u32 {
+        self.reset.max(self.newborn)
+    }
+}
+
+/// Fishes sharing a species, a timer and an age. Ages are only tracked for
+/// species that die, being 0 otherwise.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Cohort {
+    pub species: usize,
+    pub timer: u32,
+    pub age: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Lifecycle {
+    species: Vec<Species>,
+    /// Index of the first cohort of each species, cohorts being ordered by
+    /// species, then age, then timer.
+    offsets: Vec<usize>,
+    /// Index of the cohort each cohort moves to after a day unless it dies,
+    /// and of the cohort of its newborns if it gives birth.
+    successors: Vec<(Option<usize>, Option<usize>)>,
+}
+
+impl Lifecycle {
+    fn new(species: Vec<Species>) -> Self {
+        let mut offsets = Vec::with_capacity(species.len());
+        let mut len = 0;
+
+        for species in &species {
+            offsets.push(len);
+            len += (species.max_timer() as usize + 1) * species.lifespan.unwrap_or(1) as usize;
+        }
+
+        let mut lifecycle = Self {
+            species,
+            offsets,
+            successors: Vec::new(),
+        };
+
+        lifecycle.successors = lifecycle
+            .cohorts()
+            .map(|cohort| {
+                let (survivors, newborns) = lifecycle.next_day(cohort);
+
+                (
+                    survivors.map(|cohort| lifecycle.cohort_index(cohort)),
+                    newborns.map(|cohort| lifecycle.cohort_index(cohort)),
+                )
+            })
+            .collect();
+
+        lifecycle
+    }
+
+    /// The single species of the puzzle, which never dies.
+    pub fn puzzle() -> Self {
+        Self::new(vec![Species {
+            name: "lanternfish".to_string(),
+            reset: RESET_TERM as u32,
+            newborn: NEWBORN_TERM as u32,
+            lifespan: None,
+            offspring: 0,
+        }])
+    }
+
+    pub fn parse(config: &str) -> error::Result<Self> {
+        let lines: Vec<(usize, &str, &str)> = config
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+            .map(|(index, line)| {
+                let (name, settings) = line
+                    .split_once(':')
+                    .ok_or_else(|| Error::new("expected \"<species>: <settings>\""))
+                    .map_err(|err| err.at_line(index + 1))?;
+
+                Ok((index + 1, name.trim(), settings))
+            })
+            .collect::<error::Result<_>>()?;
+
+        let names: Vec<&str> = lines.iter().map(|(_, name, _)| *name).collect();
+        let mut species = Vec::with_capacity(lines.len());
+        let mut cohorts_len: usize = 0;
+
+        for (index, (line, name, settings)) in lines.iter().enumerate() {
+            if name.is_empty() {
+                return Err(Error::new("missing species name").at_line(*line));
+            }
+
+            if names[..index].contains(name) {
+                return Err(
+                    Error::new(format!("species {:?} is already defined", name)).at_line(*line)
+                );
+            }
+
+            let parsed =
+                parse_species(name, settings, index, &names).map_err(|err| err.at_line(*line))?;
+
+            cohorts_len = (parsed.max_timer() as usize)
+                .checked_add(1)
+                .and_then(|timers| timers.checked_mul(parsed.lifespan.unwrap_or(1) as usize))
+                .and_then(|cohorts| cohorts.checked_add(cohorts_len))
+                .filter(|cohorts_len| *cohorts_len <= MAX_COHORTS)
+                .ok_or_else(|| {
+                    Error::new(format!(
+                        "more than {} cohorts, timers or lifespans are too long",
+                        MAX_COHORTS
+                    ))
+                    .at_line(*line)
+                })?;
+            species.push(parsed);
+        }
+
+        if species.is_empty() {
+            return Err(Error::new("no species defined"));
+        }
+
+        Ok(Self::new(species))
+    }
+
+    pub fn species(&self) -> &[Species] {
+        &self.species
+    }
+
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        self.species.iter().position(|species| species.name == name)
+    }
+
+    /// Number of cohorts, which index the counts of a population.
+    pub fn cohorts_len(&self) -> usize {
+        self.successors.len()
+    }
+
+    /// Every cohort, in index order.
+    pub fn cohorts(&self) -> impl Iterator<Item = Cohort> + '_ {
+        self.species
+            .iter()
+            .enumerate()
+            .flat_map(|(index, species)| {
+                (0..species.lifespan.unwrap_or(1)).flat_map(move |age| {
+                    (0..=species.max_timer()).map(move |timer| Cohort {
+                        species: index,
+                        timer,
+                        age,
+                    })
+                })
+            })
+    }
+
+    /// Panics when the cohort does not exist in this lifecycle.
+    pub fn cohort_index(&self, cohort: Cohort) -> usize {
+        let species = &self.species[cohort.species];
+
+        assert!(cohort.timer <= species.max_timer() && cohort.age < species.lifespan.unwrap_or(1));
+
+        self.offsets[cohort.species]
+            + cohort.age as usize * (species.max_timer() as usize + 1)
+            + cohort.timer as usize
+    }
+
+    /// What a day does to the fishes of `cohort`: the cohort they move to
+    /// unless they die, and the cohort of their newborns if they give birth.
+    pub fn next_day(&self, cohort: Cohort) -> (Option<Cohort>, Option<Cohort>) {
+        let species = &self.species[cohort.species];
+        let age = species.lifespan.map_or(0, |_| cohort.age + 1);
+
+        let (timer, newborns) = if cohort.timer == 0 {
+            let newborns = Cohort {
+                species: species.offspring,
+                timer: self.species[species.offspring].newborn,
+                age: 0,
+            };
+
+            (species.reset, Some(newborns))
+        } else {
+            (cohort.timer - 1, None)
+        };
+
+        let survivors = match species.lifespan {
+            Some(lifespan) if age >= lifespan => None,
+            _ => Some(Cohort {
+                timer,
+                age,
+                ..cohort
+            }),
+        };
+
+        (survivors, newborns)
+    }
+
+    /// Same as [`Self::next_day`], with cohorts given by index.
+    pub fn successors(&self, index: usize) -> (Option<usize>, Option<usize>) {
+        self.successors[index]
+    }
+}
+
+impl Default for Lifecycle {
+    fn default() -> Self {
+        Self::puzzle()
+    }
+}
+
+fn parse_species(
+    name: &str,
+    settings: &str,
+    index: usize,
+    names: &[&str],
+) -> error::Result<Species> {
+    let mut reset = None;
+    let mut newborn = None;
+    let mut lifespan = None;
+    let mut offspring = index;
+
+    for setting in settings.split(',') {
+        let (key, value) = setting.trim().split_once(' ').ok_or_else(|| {
+            Error::new(format!(
+                "expected \"<setting> <value>\": {:?}",
+                setting.trim()
+            ))
+        })?;
+        let value = value.trim();
+        let number = || {
+            value
+                .parse::<u32>()
+                .map_err(|err| Error::new(format!("{} {}: {:?}", key, err, value)))
+        };
+
+        match key {
+            "reset" => reset = Some(number()?),
+            "newborn" => newborn = Some(number()?),
+            "lifespan" => match number()? {
+                0 => return Err(Error::new("lifespan must be at least 1 day")),
+                days => lifespan = Some(days),
+            },
+            "offspring" => {
+                offspring = names
+                    .iter()
+                    .position(|name| *name == value)
+                    .ok_or_else(|| Error::new(format!("unknown species {:?}", value)))?
+            }
+            _ => return Err(Error::new(format!("unknown setting {:?}", key))),
+        }
+    }
+
+    Ok(Species {
+        name: name.to_string(),
+        reset: reset.ok_or_else(|| Error::new("missing reset"))?,
+        newborn: newborn.ok_or_else(|| Error::new("missing newborn"))?,
+        lifespan,
+        offspring,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{parse, tests::EXAMPLE, Population};
+    use super::*;
+
+    #[test]
+    fn puzzle_preset() {
+        let lifecycle = Lifecycle::puzzle();
+
+        assert_eq!(
+            Lifecycle::parse("lanternfish: reset 6, newborn 8\n"),
+            Ok(lifecycle.clone())
+        );
+        assert_eq!(lifecycle.cohorts_len(), 9);
+        assert_eq!(lifecycle.successors(0), (Some(6), Some(8)));
+        assert_eq!(lifecycle.successors(8), (Some(7), None));
+
+        let population = Population::new(&parse(EXAMPLE).unwrap());
+
+        assert_eq!(population.after(80).unwrap().len(), 5934);
+    }
+
+    #[test]
+    fn cohort_indexes() {
+        let lifecycle =
+            Lifecycle::parse("a: reset 1, newborn 2\nb: reset 1, newborn 1, lifespan 2\n").unwrap();
+
+        assert_eq!(lifecycle.cohorts_len(), 3 + 2 * 2);
+
+        for (index, cohort) in lifecycle.cohorts().enumerate() {
+            assert_eq!(lifecycle.cohort_index(cohort), index);
+        }
+
+        let old = Cohort {
+            species: 1,
+            timer: 0,
+            age: 1,
+        };
+
+        // Old fishes die, but give birth first.
+        assert_eq!(
+            lifecycle.next_day(old),
+            (
+                None,
+                Some(Cohort {
+                    species: 1,
+                    timer: 1,
+                    age: 0
+                })
+            )
+        );
+    }
+
+    #[test]
+    fn parse_config() {
+        let lifecycle = Lifecycle::parse(
+            "\
+# Mayflies give birth to lanternfishes.
+lanternfish: reset 6, newborn 8
+
+mayfly: newborn 2, reset 1, lifespan 30, offspring lanternfish
+",
+        )
+        .unwrap();
+
+        assert_eq!(
+            lifecycle.species()[1],
+            Species {
+                name: "mayfly".to_string(),
+                reset: 1,
+                newborn: 2,
+                lifespan: Some(30),
+                offspring: 0,
+            }
+        );
+        assert_eq!(lifecycle.index_of("mayfly"), Some(1));
+    }
+
+    #[test]
+    fn invalid_config() {
+        let line = |config| Lifecycle::parse(config).unwrap_err().line();
+
+        assert_eq!(line("a: reset 6, newborn 8\nb reset 6\n"), Some(2));
+        assert_eq!(line("a: reset 6\n"), Some(1));
+        assert_eq!(line("a: reset 6, newborn x\n"), Some(1));
+        assert_eq!(line("a: reset 6, newborn 8, offspring b\n"), Some(1));
+        assert_eq!(line("a: reset 6, newborn 8, lifespan 0\n"), Some(1));
+        assert_eq!(
+            line("a: reset 6, newborn 8\na: reset 1, newborn 1\n"),
+            Some(2)
+        );
+        assert!(Lifecycle::parse("# nothing\n").is_err());
+    }
+
+    #[test]
+    fn too_many_cohorts() {
+        let line = |config| Lifecycle::parse(config).unwrap_err().line();
+
+        assert_eq!(
+            line("a: reset 6, newborn 8, lifespan 2000000000\n"),
+            Some(1)
+        );
+        assert_eq!(line("a: reset 4294967295, newborn 8\n"), Some(1));
+        assert_eq!(
+            line("a: reset 6, newborn 8, lifespan 100\nb: reset 6, newborn 8, lifespan 20\n"),
+            Some(2)
+        );
+        assert_eq!(
+            Lifecycle::parse("a: reset 1, newborn 1, lifespan 512\n")
+                .unwrap()
+                .cohorts_len(),
+            MAX_COHORTS
+        );
+    }
+
+    #[test]
+    fn deaths() {
+        let lifecycle = Lifecycle::parse("mayfly: reset 1, newborn 1, lifespan 3\n").unwrap();
+        let mut population = Population::empty(lifecycle);
+
+        population.add("mayfly", 0, 1).unwrap();
+
+        let mut lens = Vec::new();
+
+        for _ in 0..4 {
+            lens.push(population.len());
+            population.next_day().unwrap();
+        }
+
+        // The first fish dies on day 3, right after giving birth again.
+        assert_eq!(lens, vec![1, 2, 2, 3]);
+        assert!(population.add("mayfly", 2, 1).is_err());
+        assert!(population.add("squid", 0, 1).is_err());
+    }
+
+    #[test]
+    fn interacting_species() {
+        let lifecycle = Lifecycle::parse(
+            "\
+a: reset 2, newborn 2, offspring b
+b: reset 1, newborn 1, lifespan 1
+",
+        )
+        .unwrap();
+        let mut population = Population::empty(lifecycle);
+
+        population.add("a", 0, 5).unwrap();
+        population.next_day().unwrap();
+
+        assert_eq!(
+            (population.counts(0), population.counts(1)),
+            (vec![0, 0, 5], vec![0, 5])
+        );
+
+        population.next_day().unwrap();
+
+        assert_eq!(
+            (population.counts(0), population.counts(1)),
+            (vec![0, 5, 0], vec![0, 0])
+        );
+        assert!(!population.is_empty());
+    }
+}
@@ -0,0 +1,192 @@
+//! Population after any number of days in logarithmic time.
+//!
+//! A day of a population is a linear map over its counts per cohort, hence a
+//! square matrix. Raising it to the power `n` by squaring gives the map of
+//! `n` days in `O(log n)` matrix products. Counts are arbitrary-precision as
+//! those of the puzzle outgrow `u64` after about 450 days.
+
+use super::lifecycle::Lifecycle;
+use super::Population;
+use num_bigint::BigUint;
+
+/// How the counts per cohort evolve over some days: the count of cohort `i`
+/// afterwards is the sum over `j` of `cells[i][j]` times the count of cohort
+/// `j` before.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Transition {
+    cells: Vec<Vec<BigUint>>,
+}
+
+impl Transition {
+    /// Leaves the `size` counts as they are.
+    pub fn identity(size: usize) -> Self {
+        Self {
+            cells: (0..size)
+                .map(|i| (0..size).map(|j| BigUint::from((i == j) as u8)).collect())
+                .collect(),
+        }
+    }
+
+    /// A single day of `lifecycle`.
+    pub fn day(lifecycle: &Lifecycle) -> Self {
+        let size = lifecycle.cohorts_len();
+        let mut cells = vec![vec![BigUint::default(); size]; size];
+
+        for (from, (survivors, newborns)) in
+            (0..size).map(|from| (from, lifecycle.successors(from)))
+        {
+            for to in [survivors, newborns].into_iter().flatten() {
+                cells[to][from] += 1_u8;
+            }
+        }
+
+        Self { cells }
+    }
+
+    /// `days` days of `lifecycle` in a row.
+    pub fn days(lifecycle: &Lifecycle, mut days: u64) -> Self {
+        let mut result = Self::identity(lifecycle.cohorts_len());
+        let mut power = Self::day(lifecycle);
+
+        while days > 0 {
+            if days & 1 == 1 {
+                result = result.then(&power);
+            }
+
+            days >>= 1;
+
+            if days > 0 {
+                power = power.then(&power);
+            }
+        }
+
+        result
+    }
+
+    /// `self` followed by `other`.
+    pub fn then(&self, other: &Transition) -> Self {
+        let size = self.cells.len();
+
+        Self {
+            cells: (0..size)
+                .map(|i| {
+                    (0..size)
+                        .map(|j| {
+                            (0..size)
+                                .map(|k| &other.cells[i][k] * &self.cells[k][j])
+                                .sum()
+                        })
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+
+    /// Counts per cohort once the transition is applied to `population`,
+    /// which must follow the lifecycle of the transition.
+    pub fn apply(&self, population: &Population) -> Vec<BigUint> {
+        let counts: Vec<BigUint> = population
+            .cohort_counts()
+            .iter()
+            .map(|count| BigUint::from(*count))
+            .collect();
+
+        self.apply_counts(&counts)
+    }
+
+    /// Same as [`Self::apply`], with counts per cohort already outgrowing
+    /// `u64`.
+    pub fn apply_counts(&self, counts: &[BigUint]) -> Vec<BigUint> {
+        self.cells
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .zip(counts)
+                    .map(|(cell, count)| cell * count)
+                    .sum()
+            })
+            .collect()
+    }
+}
+
+/// Number of fishes of `population` after `days` days, however large.
+pub fn population_after(population: &Population, days: u64) -> BigUint {
+    Transition::days(population.lifecycle(), days)
+        .apply(population)
+        .into_iter()
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{parse, tests::EXAMPLE};
+    use super::*;
+
+    #[test]
+    fn example() {
+        let population = Population::new(&parse(EXAMPLE).unwrap());
+
+        assert_eq!(population_after(&population, 18), BigUint::from(26u8));
+        assert_eq!(population_after(&population, 80), BigUint::from(5934u16));
+        assert_eq!(
+            population_after(&population, 256),
+            BigUint::from(26984457539u64)
+        );
+    }
+
+    #[test]
+    fn matches_population() {
+        let initial = Population::new(&parse(EXAMPLE).unwrap());
+        let mut population = initial.clone();
+
+        for days in 0..=100 {
+            let counts = Transition::days(initial.lifecycle(), days).apply(&initial);
+
+            assert_eq!(
+                counts,
+                population
+                    .cohort_counts()
+                    .iter()
+                    .map(|count| BigUint::from(*count))
+                    .collect::<Vec<_>>(),
+                "after {} days",
+                days
+            );
+
+            population.next_day().unwrap();
+        }
+    }
+
+    #[test]
+    fn other_lifecycles() {
+        let lifecycle = Lifecycle::parse(
+            "\
+a: reset 2, newborn 3, offspring b
+b: reset 1, newborn 1, lifespan 4
+",
+        )
+        .unwrap();
+        let mut population = Population::empty(lifecycle);
+
+        population.add("a", 1, 3).unwrap();
+        population.add("b", 0, 2).unwrap();
+
+        assert_eq!(
+            population_after(&population, 30),
+            BigUint::from(population.clone().after(30).unwrap().len())
+        );
+    }
+
+    #[test]
+    fn beyond_u64() {
+        let population = Population::new(&parse(EXAMPLE).unwrap());
+        let count = population_after(&population, 10_000);
+
+        assert!(count > BigUint::from(u64::MAX));
+        // Each day is the sum of the counts of 7 and 9 days before.
+        assert_eq!(
+            count,
+            population_after(&population, 10_000 - 7) + population_after(&population, 10_000 - 9)
+        );
+    }
+}
@@ -0,0 +1,352 @@
+use crate::error::{self, Error};
+use crate::solution::{Answer, Part, Solution, Variant};
+use lifecycle::{Cohort, Lifecycle};
+use std::{
+    fmt::{Display, Formatter},
+    num::ParseIntError,
+    str::FromStr,
+};
+
+pub mod lifecycle;
+pub mod matrix;
+pub mod series;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    const INPUT: &'static str = include_str!("input.txt");
+    const ANSWERS: &'static str = include_str!("answers.txt");
+
+    type Input = Vec<Lanternfish>;
+
+    fn parse(input: &str) -> error::Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(fishes: &Self::Input) -> error::Result<Answer> {
+        Ok(part1(fishes)?.into())
+    }
+
+    fn part2(fishes: &Self::Input) -> error::Result<Answer> {
+        Ok(part2(fishes)?.into())
+    }
+
+    fn variants() -> Vec<Variant<Self::Input>> {
+        vec![
+            Variant {
+                part: Part::One,
+                name: "matrix",
+                solve: |fishes| Ok(matrix::population_after(&Population::new(fishes), 80).into()),
+            },
+            Variant {
+                part: Part::Two,
+                name: "matrix",
+                solve: |fishes| Ok(matrix::population_after(&Population::new(fishes), 256).into()),
+            },
+        ]
+    }
+}
+
+/// Timer of a lanternfish right after it was born.
+const NEWBORN_TERM: u8 = 8;
+/// Timer of a lanternfish right after it gave birth.
+const RESET_TERM: u8 = 6;
+
+pub fn parse(input: &str) -> error::Result<Vec<Lanternfish>> {
+    error::parse_separated(input.trim_end(), ',').map_err(|err| err.at_line(1))
+}
+
+pub fn part1(fishes: &[Lanternfish]) -> error::Result<u64> {
+    Ok(Population::new(fishes).after(80)?.len())
+}
+
+pub fn part2(fishes: &[Lanternfish]) -> error::Result<u64> {
+    Ok(Population::new(fishes).after(256)?.len())
+}
+
+#[derive(Clone, Debug)]
+pub struct Lanternfish {
+    term: u8,
+}
+
+impl Lanternfish {
+    #[cfg(test)]
+    fn newborn() -> Self {
+        Lanternfish { term: NEWBORN_TERM }
+    }
+
+    #[cfg(test)]
+    fn next_day(&mut self) -> Option<Lanternfish> {
+        if self.term == 0 {
+            self.term = RESET_TERM;
+
+            Some(Self::newborn())
+        } else {
+            self.term -= 1;
+
+            None
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParseLanternfishError {
+    InvalidNumber(ParseIntError),
+    TermTooLong(u8),
+}
+
+impl Display for ParseLanternfishError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Self::InvalidNumber(err) => write!(f, "{}", err),
+            Self::TermTooLong(term) => write!(
+                f,
+                "timer {} is longer than the {} days of a newborn",
+                term, NEWBORN_TERM
+            ),
+        }
+    }
+}
+
+impl From<ParseIntError> for ParseLanternfishError {
+    fn from(err: ParseIntError) -> Self {
+        ParseLanternfishError::InvalidNumber(err)
+    }
+}
+
+impl FromStr for Lanternfish {
+    type Err = ParseLanternfishError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let term = s.parse()?;
+
+        if term > NEWBORN_TERM {
+            return Err(ParseLanternfishError::TermTooLong(term));
+        }
+
+        Ok(Lanternfish { term })
+    }
+}
+
+/// A school of fishes following a [`Lifecycle`], counted per cohort rather
+/// than one by one. Its size is kept within `u64`, [`matrix::population_after`]
+/// counting larger schools.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Population {
+    lifecycle: Lifecycle,
+    /// Number of fishes of each cohort, by cohort index.
+    counts: Vec<u64>,
+}
+
+impl Population {
+    /// The fishes of the puzzle, with its lifecycle.
+    pub fn new(fishes: &[Lanternfish]) -> Self {
+        let mut population = Self::empty(Lifecycle::puzzle());
+
+        for fish in fishes {
+            population.counts[fish.term as usize] += 1;
+        }
+
+        population
+    }
+
+    pub fn empty(lifecycle: Lifecycle) -> Self {
+        Self {
+            counts: vec![0; lifecycle.cohorts_len()],
+            lifecycle,
+        }
+    }
+
+    /// Adds `count` fishes of `species` with the given timer, considered to
+    /// be newborns if their species dies.
+    pub fn add(&mut self, species: &str, timer: u32, count: u64) -> error::Result<()> {
+        let index = self
+            .lifecycle
+            .index_of(species)
+            .ok_or_else(|| Error::new(format!("unknown species {:?}", species)))?;
+        let max_timer = self.lifecycle.species()[index].max_timer();
+
+        if timer > max_timer {
+            return Err(Error::new(format!(
+                "timer {} of {} is longer than its {} days",
+                timer, species, max_timer
+            )));
+        }
+
+        self.len().checked_add(count).ok_or_else(overflow_error)?;
+        self.counts[self.lifecycle.cohort_index(Cohort {
+            species: index,
+            timer,
+            age: 0,
+        })] += count;
+
+        Ok(())
+    }
+
+    pub fn lifecycle(&self) -> &Lifecycle {
+        &self.lifecycle
+    }
+
+    /// Fails, leaving the population unchanged, when it would outgrow `u64`.
+    pub fn next_day(&mut self) -> error::Result<()> {
+        let mut counts = vec![0; self.counts.len()];
+        let mut len: u64 = 0;
+
+        for (index, count) in self.counts.iter().enumerate() {
+            let (survivors, newborns) = self.lifecycle.successors(index);
+
+            for successor in [survivors, newborns].into_iter().flatten() {
+                // Counts never exceed the total, which cannot overflow then.
+                len = len.checked_add(*count).ok_or_else(overflow_error)?;
+                counts[successor] += count;
+            }
+        }
+
+        self.counts = counts;
+
+        Ok(())
+    }
+
+    pub fn after(mut self, days: usize) -> error::Result<Self> {
+        for day in 1..=days {
+            self.next_day()
+                .map_err(|err| Error::new(format!("day {}: {}", day, err.message())))?;
+        }
+
+        Ok(self)
+    }
+
+    /// Number of fishes of each cohort, by cohort index.
+    pub fn cohort_counts(&self) -> &[u64] {
+        &self.counts
+    }
+
+    /// Number of fishes of the species at `species` per timer value.
+    pub fn counts(&self, species: usize) -> Vec<u64> {
+        let mut counts = vec![0; self.lifecycle.species()[species].max_timer() as usize + 1];
+
+        for (cohort, count) in self.lifecycle.cohorts().zip(&self.counts) {
+            if cohort.species == species {
+                counts[cohort.timer as usize] += count;
+            }
+        }
+
+        counts
+    }
+
+    pub fn len(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+fn overflow_error() -> Error {
+    Error::new(format!(
+        "the school outgrows {} fishes, count it with the matrix variant",
+        u64::MAX
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    pub(super) const EXAMPLE: &str = "\
+3,4,3,1,2
+";
+
+    /// Reference model, simulating every fish on its own.
+    fn simulate_fishes(fishes: &[Lanternfish], days: usize) -> Vec<Lanternfish> {
+        let mut fishes = fishes.to_vec();
+
+        for _ in 0..days {
+            let newborns: Vec<_> = fishes.iter_mut().flat_map(|fish| fish.next_day()).collect();
+            fishes.extend(newborns);
+        }
+
+        fishes
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), Ok(5934));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), Ok(26984457539));
+    }
+
+    #[test]
+    fn overflow() {
+        let population = Population::new(&parse(EXAMPLE).unwrap());
+        let mut last = population.clone();
+        let mut days = 0;
+
+        while last.next_day().is_ok() {
+            days += 1;
+        }
+
+        // The population is left as it was on the last day it fits.
+        assert_eq!(last, population.clone().after(days).unwrap());
+        assert!(days > 400 && days < 500, "{}", days);
+
+        let err = population.after(600).unwrap_err();
+
+        assert!(
+            err.message().starts_with(&format!("day {}:", days + 1)),
+            "{}",
+            err
+        );
+
+        let mut population = Population::empty(Lifecycle::puzzle());
+
+        population.add("lanternfish", 0, u64::MAX).unwrap();
+        assert!(population.add("lanternfish", 1, 1).is_err());
+    }
+
+    #[test]
+    fn population_matches_fishes() {
+        let fishes = parse(EXAMPLE).unwrap();
+        let mut population = Population::new(&fishes);
+
+        for days in 0..=80 {
+            assert_eq!(
+                population,
+                Population::new(&simulate_fishes(&fishes, days)),
+                "after {} days",
+                days
+            );
+
+            population.next_day().unwrap();
+        }
+    }
+
+    #[test]
+    fn invalid_term() {
+        assert_eq!(
+            "9".parse::<Lanternfish>().unwrap_err(),
+            ParseLanternfishError::TermTooLong(9)
+        );
+
+        let err = parse("3,4,12\n").unwrap_err();
+
+        assert_eq!((err.line(), err.column()), (Some(1), Some(5)));
+    }
+
+    #[test]
+    fn lanterfish_test() {
+        let mut fish = Lanternfish::newborn();
+
+        for _ in 0..8 {
+            assert!(fish.next_day().is_none());
+        }
+
+        assert!(matches!(fish.next_day(), Some(Lanternfish { term: 8 })));
+        assert!(matches!(fish, Lanternfish { term: 6 }));
+    }
+}
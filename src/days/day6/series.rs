@@ -0,0 +1,244 @@
+//! Population of every day over a range, to chart how the school grows.
+//!
+//! Counts are arbitrary-precision, so that the series can go on long after
+//! they outgrow `u64`.
+
+use super::lifecycle::Lifecycle;
+use super::matrix::Transition;
+use super::Population;
+use num_bigint::BigUint;
+use std::io::{self, Write};
+use std::ops::Range;
+
+/// The school at the end of a day, `day` 0 being the initial state.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Snapshot {
+    pub day: usize,
+    /// Number of fishes per timer value, for each species.
+    pub counts: Vec<Vec<BigUint>>,
+}
+
+impl Snapshot {
+    pub fn total(&self) -> BigUint {
+        self.counts.iter().flatten().sum()
+    }
+}
+
+/// Iterator over the snapshots of a range of days.
+#[derive(Clone, Debug)]
+pub struct Series {
+    lifecycle: Lifecycle,
+    next_day: Transition,
+    /// Counts per cohort on the next day of the range.
+    counts: Vec<BigUint>,
+    days: Range<usize>,
+}
+
+impl Iterator for Series {
+    type Item = Snapshot;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let day = self.days.next()?;
+        let mut counts: Vec<Vec<BigUint>> = self
+            .lifecycle
+            .species()
+            .iter()
+            .map(|species| vec![BigUint::default(); species.max_timer() as usize + 1])
+            .collect();
+
+        for (cohort, count) in self.lifecycle.cohorts().zip(&self.counts) {
+            counts[cohort.species][cohort.timer as usize] += count;
+        }
+
+        self.counts = self.next_day.apply_counts(&self.counts);
+
+        Some(Snapshot { day, counts })
+    }
+}
+
+/// Snapshots of `population` over `days`, counted from its current state.
+pub fn series(population: &Population, days: Range<usize>) -> Series {
+    let lifecycle = population.lifecycle();
+
+    Series {
+        lifecycle: lifecycle.clone(),
+        next_day: Transition::day(lifecycle),
+        counts: Transition::days(lifecycle, days.start as u64).apply(population),
+        days,
+    }
+}
+
+/// Writes one row per snapshot, with the total and then the count of each
+/// timer value. Columns are prefixed with the name of their species when
+/// there are several.
+pub fn write_csv<W, I>(mut w: W, lifecycle: &Lifecycle, snapshots: I) -> io::Result<()>
+where
+    W: Write,
+    I: IntoIterator<Item = Snapshot>,
+{
+    write!(w, "day,total")?;
+
+    for species in lifecycle.species() {
+        for timer in 0..=species.max_timer() {
+            match lifecycle.species().len() {
+                1 => write!(w, ",timer{}", timer)?,
+                _ => write!(w, ",{}_timer{}", species.name, timer)?,
+            }
+        }
+    }
+
+    writeln!(w)?;
+
+    for snapshot in snapshots {
+        write!(w, "{},{}", snapshot.day, snapshot.total())?;
+
+        for count in snapshot.counts.iter().flatten() {
+            write!(w, ",{}", count)?;
+        }
+
+        writeln!(w)?;
+    }
+
+    Ok(())
+}
+
+/// Average daily growth factor between the first and last snapshots, when
+/// both have fishes.
+pub fn growth_rate(snapshots: &[Snapshot]) -> Option<f64> {
+    let (first, last) = (snapshots.first()?, snapshots.last()?);
+
+    if last.day <= first.day || first.total() == BigUint::default() {
+        return None;
+    }
+
+    let days = (last.day - first.day) as f64;
+
+    Some(((ln(&last.total()) - ln(&first.total())) / days).exp())
+}
+
+/// Natural logarithm of `n`, from its 64 most significant bits.
+fn ln(n: &BigUint) -> f64 {
+    let shift = n.bits().saturating_sub(64);
+    let top = u64::try_from(n >> shift).expect("64 bits at most");
+
+    (top as f64).ln() + shift as f64 * std::f64::consts::LN_2
+}
+
+/// Daily growth factor a population following `lifecycle` tends to, found
+/// by power iteration: starting from one fish per cohort, the ratio between
+/// the totals of two consecutive days converges to it.
+///
+/// Lifecycles whose births all happen on a common period other than 1 may
+/// oscillate rather than converge.
+pub fn model_growth_rate(lifecycle: &Lifecycle) -> f64 {
+    let mut counts = vec![1.0; lifecycle.cohorts_len()];
+    let mut rate = 0.0;
+
+    for _ in 0..10_000 {
+        let mut next = vec![0.0; counts.len()];
+
+        for (index, count) in counts.iter().enumerate() {
+            let (survivors, newborns) = lifecycle.successors(index);
+
+            for successor in [survivors, newborns].into_iter().flatten() {
+                next[successor] += count;
+            }
+        }
+
+        let total: f64 = next.iter().sum();
+
+        if total == 0.0 {
+            return 0.0;
+        }
+
+        rate = total / counts.iter().sum::<f64>();
+        counts = next.iter().map(|count| count / total).collect();
+    }
+
+    rate
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{matrix::population_after, parse, tests::EXAMPLE};
+    use super::*;
+
+    fn example() -> Population {
+        Population::new(&parse(EXAMPLE).unwrap())
+    }
+
+    #[test]
+    fn example_series() {
+        let totals: Vec<BigUint> = series(&example(), 0..19)
+            .map(|snapshot| snapshot.total())
+            .collect();
+
+        assert_eq!(totals.len(), 19);
+        assert_eq!(totals[..4], [5_u8, 5, 6, 7].map(BigUint::from));
+        assert_eq!(totals[18], BigUint::from(26_u8));
+
+        let snapshot = series(&example(), 80..81).next().unwrap();
+
+        assert_eq!(
+            (snapshot.day, snapshot.total()),
+            (80, BigUint::from(5934_u16))
+        );
+        assert_eq!(series(&example(), 5..5).count(), 0);
+    }
+
+    #[test]
+    fn beyond_u64() {
+        let last = series(&example(), 0..1001).last().unwrap();
+
+        assert_eq!(last.day, 1000);
+        assert_eq!(last.total(), population_after(&example(), 1000));
+        assert!(last.total() > BigUint::from(u64::MAX));
+    }
+
+    #[test]
+    fn csv() {
+        let mut csv = Vec::new();
+
+        write_csv(&mut csv, &Lifecycle::puzzle(), series(&example(), 0..2)).unwrap();
+
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "\
+day,total,timer0,timer1,timer2,timer3,timer4,timer5,timer6,timer7,timer8
+0,5,0,1,1,2,1,0,0,0,0
+1,5,1,1,2,1,0,0,0,0,0
+"
+        );
+
+        let lifecycle = Lifecycle::parse("a: reset 1, newborn 1\nb: reset 0, newborn 0\n").unwrap();
+        let mut population = Population::empty(lifecycle.clone());
+        let mut csv = Vec::new();
+
+        population.add("b", 0, 3).unwrap();
+        write_csv(&mut csv, &lifecycle, series(&population, 0..1)).unwrap();
+
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "day,total,a_timer0,a_timer1,b_timer0\n0,3,0,0,3\n"
+        );
+    }
+
+    #[test]
+    fn growth_matches_model() {
+        let snapshots: Vec<Snapshot> = series(&example(), 200..257).collect();
+        let rate = growth_rate(&snapshots).unwrap();
+        let model = model_growth_rate(&Lifecycle::puzzle());
+
+        // The real root of x^9 = x^2 + 1, as each day counts the fishes of 7
+        // and 9 days before.
+        assert!((model - 1.0910).abs() < 1e-4, "{}", model);
+        assert!((model.powi(9) - model.powi(2) - 1.0).abs() < 1e-9);
+        assert!((rate - model).abs() < 1e-3, "{}", rate);
+        assert_eq!(growth_rate(&snapshots[..1]), None);
+
+        let snapshots: Vec<Snapshot> = series(&example(), 9000..10001).collect();
+        let rate = growth_rate(&snapshots).unwrap();
+
+        assert!((rate - model).abs() < 1e-9, "{}", rate);
+    }
+}
@@ -0,0 +1,262 @@
+use crate::error::{self, Error};
+use crate::optimize;
+use crate::solution::{Answer, Part, Solution, Variant};
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    const INPUT: &'static str = include_str!("input.txt");
+    const ANSWERS: &'static str = include_str!("answers.txt");
+
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> error::Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(positions: &Self::Input) -> error::Result<Answer> {
+        part1_algo1(positions).map(Answer::from)
+    }
+
+    fn part2(positions: &Self::Input) -> error::Result<Answer> {
+        part2_algo1(positions).map(Answer::from)
+    }
+
+    fn variants() -> Vec<Variant<Self::Input>> {
+        vec![
+            Variant {
+                part: Part::One,
+                name: "algo2",
+                solve: |positions| part1_algo2(positions).map(Answer::from),
+            },
+            Variant {
+                part: Part::Two,
+                name: "algo2",
+                solve: |positions| part2_algo2(positions).map(Answer::from),
+            },
+            Variant {
+                part: Part::One,
+                name: "algo3",
+                solve: |positions| part1_algo3(positions).map(Answer::from),
+            },
+            Variant {
+                part: Part::Two,
+                name: "algo3",
+                solve: |positions| part2_algo3(positions).map(Answer::from),
+            },
+        ]
+    }
+}
+
+pub fn parse(input: &str) -> error::Result<Vec<i64>> {
+    error::parse_separated(input.trim_end(), ',').map_err(|err| err.at_line(1))
+}
+
+pub fn part1_algo1(positions: &[i64]) -> error::Result<i64> {
+    part_algo1(positions, |distance| distance)
+}
+
+pub fn part2_algo1(positions: &[i64]) -> error::Result<i64> {
+    part_algo1(positions, n_first_int_sum)
+}
+
+pub fn part1_algo2(positions: &[i64]) -> error::Result<i64> {
+    part_algo2(positions, |distance| distance)
+}
+
+pub fn part2_algo2(positions: &[i64]) -> error::Result<i64> {
+    part_algo2(positions, n_first_int_sum)
+}
+
+/// The median minimizes the sum of distances.
+pub fn part1_algo3(positions: &[i64]) -> error::Result<i64> {
+    let mut sorted = positions.to_vec();
+    sorted.sort_unstable();
+
+    let median = *sorted
+        .get(sorted.len() / 2)
+        .ok_or_else(|| Error::new("no crab positions"))?;
+
+    Ok(total_cost(positions, median, |distance| distance))
+}
+
+/// The sum of triangular costs is minimized within half a step of the mean,
+/// hence at its floor or at its ceiling.
+pub fn part2_algo3(positions: &[i64]) -> error::Result<i64> {
+    if positions.is_empty() {
+        return Err(Error::new("no crab positions"));
+    }
+
+    let sum: i64 = positions.iter().sum();
+    let len = positions.len() as i64;
+    let floor = sum.div_euclid(len);
+    let ceil = floor + (sum.rem_euclid(len) != 0) as i64;
+
+    Ok(
+        total_cost(positions, floor, n_first_int_sum).min(total_cost(
+            positions,
+            ceil,
+            n_first_int_sum,
+        )),
+    )
+}
+
+pub fn part_algo1<F>(positions: &[i64], cost: F) -> error::Result<i64>
+where
+    F: Fn(i64) -> i64,
+{
+    let (lower_bound, upper_bound) = bounds(positions)?;
+
+    let minimum = optimize::minimize(lower_bound..=upper_bound, |selected| {
+        total_cost(positions, selected, &cost)
+    })
+    .expect("bounds are ordered");
+
+    Ok(minimum.value)
+}
+
+pub fn part_algo2<F>(positions: &[i64], cost: F) -> error::Result<i64>
+where
+    F: Fn(i64) -> i64,
+{
+    let (leftest_pos, rightest_pos) = bounds(positions)?;
+
+    Ok((leftest_pos..=rightest_pos)
+        .map(|selected| {
+            positions
+                .iter()
+                .map(|pos| cost((selected - pos).abs()))
+                .sum()
+        })
+        .min()
+        .unwrap_or_default())
+}
+
+fn total_cost<F>(positions: &[i64], selected: i64, cost: F) -> i64
+where
+    F: Fn(i64) -> i64,
+{
+    positions
+        .iter()
+        .map(|pos| cost((selected - pos).abs()))
+        .sum()
+}
+
+fn bounds(positions: &[i64]) -> error::Result<(i64, i64)> {
+    let min = positions
+        .iter()
+        .min()
+        .ok_or_else(|| Error::new("no crab positions"))?;
+    let max = positions
+        .iter()
+        .max()
+        .ok_or_else(|| Error::new("no crab positions"))?;
+
+    Ok((*min, *max))
+}
+
+/// Cf. https://fr.wikipedia.org/wiki/Somme_(arithm%C3%A9tique)#Somme_des_premiers_entiers
+pub fn n_first_int_sum(n: i64) -> i64 {
+    (n * (n + 1)) / 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Rng;
+
+    const EXAMPLE: &str = "\
+16,1,2,0,4,2,7,1,2,14
+";
+
+    #[test]
+    fn part1_algo1_example() {
+        assert_eq!(part1_algo1(&parse(EXAMPLE).unwrap()).unwrap(), 37);
+    }
+
+    #[test]
+    fn part2_algo1_example() {
+        assert_eq!(part2_algo1(&parse(EXAMPLE).unwrap()).unwrap(), 168);
+    }
+
+    #[test]
+    fn part1_algo2_example() {
+        assert_eq!(part1_algo2(&parse(EXAMPLE).unwrap()).unwrap(), 37);
+    }
+
+    #[test]
+    fn part2_algo2_example() {
+        assert_eq!(part2_algo2(&parse(EXAMPLE).unwrap()).unwrap(), 168);
+    }
+
+    #[test]
+    fn algo1_matches_algo2() {
+        let mut rng = Rng::new(11);
+
+        for _ in 0..500 {
+            let len = 1 + rng.below(20) as usize;
+            let positions: Vec<i64> = (0..len).map(|_| rng.below(50) as i64).collect();
+
+            assert_eq!(part1_algo1(&positions), part1_algo2(&positions));
+            assert_eq!(part2_algo1(&positions), part2_algo2(&positions));
+        }
+    }
+
+    #[test]
+    fn part1_algo3_example() {
+        assert_eq!(part1_algo3(&parse(EXAMPLE).unwrap()).unwrap(), 37);
+    }
+
+    #[test]
+    fn part2_algo3_example() {
+        assert_eq!(part2_algo3(&parse(EXAMPLE).unwrap()).unwrap(), 168);
+    }
+
+    #[test]
+    fn algo3_matches_algo2() {
+        let mut rng = Rng::new(7);
+
+        for _ in 0..500 {
+            let len = 1 + rng.below(20) as usize;
+            let spread = 1 + rng.below(100);
+            let positions: Vec<i64> = (0..len)
+                .map(|_| rng.below(spread) as i64 - spread as i64 / 2)
+                .collect();
+
+            assert_eq!(
+                part1_algo3(&positions),
+                part1_algo2(&positions),
+                "{:?}",
+                positions
+            );
+            assert_eq!(
+                part2_algo3(&positions),
+                part2_algo2(&positions),
+                "{:?}",
+                positions
+            );
+        }
+    }
+
+    #[test]
+    fn no_positions() {
+        assert!(part1_algo1(&[]).is_err());
+        assert!(part2_algo2(&[]).is_err());
+        assert!(part1_algo3(&[]).is_err());
+        assert!(part2_algo3(&[]).is_err());
+    }
+
+    #[test]
+    fn test_n_first_int_sum() {
+        assert_eq!(n_first_int_sum(1), 1);
+        assert_eq!(n_first_int_sum(2), 3);
+        assert_eq!(n_first_int_sum(3), 6);
+        assert_eq!(n_first_int_sum(4), 10);
+        assert_eq!(n_first_int_sum(100), 5050);
+
+        assert_eq!(n_first_int_sum((16i64 - 5).abs()), 66);
+        assert_eq!(n_first_int_sum((1i64 - 5).abs()), 10);
+        assert_eq!(n_first_int_sum((14i64 - 5).abs()), 45);
+    }
+}
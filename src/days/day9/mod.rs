@@ -0,0 +1,144 @@
+use crate::error;
+use crate::grid::{Grid, ParseGridError};
+use crate::solution::{Answer, Solution};
+use std::{collections::HashSet, str::FromStr};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    const INPUT: &'static str = include_str!("input.txt");
+    const ANSWERS: &'static str = include_str!("answers.txt");
+
+    type Input = HeightMap;
+
+    fn parse(input: &str) -> error::Result<Self::Input> {
+        Ok(HeightMap(Grid::parse_digits(input)?))
+    }
+
+    fn part1(height_map: &Self::Input) -> error::Result<Answer> {
+        Ok(part1(height_map).into())
+    }
+
+    fn part2(height_map: &Self::Input) -> error::Result<Answer> {
+        Ok(part2(height_map).into())
+    }
+}
+
+pub fn part1(height_map: &HeightMap) -> u64 {
+    height_map
+        .low_points()
+        .iter()
+        .map(|(_, measure)| measure + 1)
+        .sum()
+}
+
+pub fn part2(height_map: &HeightMap) -> usize {
+    let basins = height_map.basins();
+
+    let mut basin_sizes: Vec<_> = basins.into_iter().map(|basin| basin.len()).collect();
+
+    basin_sizes.sort_unstable();
+
+    basin_sizes.iter().rev().take(3).product()
+}
+
+#[derive(Debug)]
+pub struct HeightMap(Grid<u8>);
+
+impl HeightMap {
+    fn low_points(&self) -> Vec<(usize, u64)> {
+        let grid = &self.0;
+
+        grid.cells()
+            .iter()
+            .enumerate()
+            .filter(|(index, measure)| {
+                grid.neighbours4(*index)
+                    .all(|adj_index| grid[adj_index] > **measure)
+            })
+            .map(|(index, measure)| (index, u64::from(*measure)))
+            .collect()
+    }
+
+    fn basins(&self) -> Vec<Vec<(usize, u64)>> {
+        self.low_points()
+            .iter()
+            .map(|(index, _)| {
+                let mut visited = HashSet::new();
+                self.scan_basin(*index, &mut visited);
+                visited
+                    .into_iter()
+                    .map(|i| (i, u64::from(self.0[i])))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn scan_basin(&self, index: usize, visited: &mut HashSet<usize>) {
+        visited.insert(index);
+
+        let newly_visited: Vec<_> = self
+            .0
+            .neighbours4(index)
+            .filter(|adj_index| self.0[*adj_index] < 9)
+            .filter(|adj_index| visited.insert(*adj_index))
+            .collect();
+
+        newly_visited
+            .iter()
+            .for_each(|new_index| self.scan_basin(*new_index, visited));
+    }
+}
+
+#[derive(Debug)]
+pub enum ParseHeightMapError {
+    InvalidInput,
+    InvalidMeasure,
+}
+
+impl From<ParseGridError> for ParseHeightMapError {
+    fn from(err: ParseGridError) -> Self {
+        match err {
+            ParseGridError::Empty | ParseGridError::RaggedRow { .. } => Self::InvalidInput,
+            ParseGridError::InvalidDigit { .. } => Self::InvalidMeasure,
+        }
+    }
+}
+
+impl FromStr for HeightMap {
+    type Err = ParseHeightMapError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(Grid::parse_digits(s)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+2199943210
+3987894921
+9856789892
+8767896789
+9899965678
+";
+
+    #[test]
+    fn test_parse_height_map() {
+        let result: Result<HeightMap, _> = EXAMPLE.parse();
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&EXAMPLE.parse().unwrap()), 15);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&EXAMPLE.parse().unwrap()), 1134);
+    }
+}
@@ -0,0 +1,36 @@
+use crate::solution::DynSolution;
+
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day13;
+pub mod day14;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day9;
+
+/// Every solved day, in calendar order.
+pub fn all() -> Vec<&'static dyn DynSolution> {
+    vec![
+        &day1::Day1,
+        &day2::Day2,
+        &day3::Day3,
+        &day4::Day4,
+        &day5::Day5,
+        &day6::Day6,
+        &day7::Day7,
+        &day9::Day9,
+        &day10::Day10,
+        &day11::Day11,
+        &day13::Day13,
+        &day14::Day14,
+    ]
+}
+
+pub fn get(day: u8) -> Option<&'static dyn DynSolution> {
+    all().into_iter().find(|solution| solution.day() == day)
+}
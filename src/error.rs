@@ -0,0 +1,155 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Error raised while solving a day, located in the input when possible.
+///
+/// Lines and columns are 1-based, as shown by editors.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Error {
+    day: Option<u8>,
+    line: Option<usize>,
+    column: Option<usize>,
+    message: String,
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    pub fn new<M: Into<String>>(message: M) -> Self {
+        Self {
+            day: None,
+            line: None,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn in_day(mut self, day: u8) -> Self {
+        self.day = Some(day);
+        self
+    }
+
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    pub fn at_column(mut self, column: usize) -> Self {
+        self.column = Some(column);
+        self
+    }
+
+    pub fn day(&self) -> Option<u8> {
+        self.day
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    pub fn column(&self) -> Option<usize> {
+        self.column
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {}: ", day)?;
+        }
+
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {}, column {}: ", line, column)?,
+            (Some(line), None) => write!(f, "line {}: ", line)?,
+            (None, Some(column)) => write!(f, "column {}: ", column)?,
+            (None, None) => (),
+        }
+
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Parses every line of `input` as a `T`, reporting the first invalid one.
+pub fn parse_lines<T>(input: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            line.parse()
+                .map_err(|err| Error::new(format!("{}: {:?}", err, line)).at_line(index + 1))
+        })
+        .collect()
+}
+
+/// Parses the `separator` delimited values of a single line, reporting the
+/// column of the first invalid one.
+pub fn parse_separated<T>(line: &str, separator: char) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let mut column = 1;
+
+    line.split(separator)
+        .map(|value| {
+            let value_column = column + (value.len() - value.trim_start().len());
+            column += value.len() + separator.len_utf8();
+
+            value.trim().parse().map_err(|err| {
+                Error::new(format!("{}: {:?}", err, value.trim())).at_column(value_column)
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        assert_eq!(Error::new("oops").to_string(), "oops");
+        assert_eq!(
+            Error::new("oops").at_line(3).in_day(5).to_string(),
+            "day 5: line 3: oops"
+        );
+        assert_eq!(
+            Error::new("oops").at_line(3).at_column(7).to_string(),
+            "line 3, column 7: oops"
+        );
+    }
+
+    #[test]
+    fn parse_lines_reports_line() {
+        assert_eq!(parse_lines::<u8>("1\n2\n"), Ok(vec![1, 2]));
+
+        let err = parse_lines::<u8>("1\n2\nthree\n").unwrap_err();
+
+        assert_eq!(err.line(), Some(3));
+        assert_eq!(err.column(), None);
+    }
+
+    #[test]
+    fn parse_separated_reports_column() {
+        assert_eq!(parse_separated::<u8>("1,22, 3", ','), Ok(vec![1, 22, 3]));
+
+        let err = parse_separated::<u8>("1,22,x3", ',').unwrap_err();
+
+        assert_eq!(err.column(), Some(6));
+        assert_eq!(err.line(), None);
+
+        let err = parse_separated::<u8>("1, x", ',').unwrap_err();
+
+        assert_eq!(err.column(), Some(4));
+    }
+}
@@ -0,0 +1,387 @@
+use crate::error::Error;
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+/// A rectangular grid stored as a flat, row-major `Vec`.
+///
+/// Cells can be addressed either by their flat index or by `(x, y)`
+/// coordinates, `x` being the column and `y` the row.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum GridError {
+    /// The number of cells does not match `width * height`.
+    SizeMismatch { expected: usize, actual: usize },
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseGridError {
+    Empty,
+    /// The row at `line` (0-based) does not have the same length as the first one.
+    RaggedRow {
+        line: usize,
+    },
+    /// The character at `line`/`column` (0-based) is not a decimal digit.
+    InvalidDigit {
+        line: usize,
+        column: usize,
+    },
+}
+
+impl Display for ParseGridError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty grid"),
+            Self::RaggedRow { .. } => write!(f, "row length differs from the first row"),
+            Self::InvalidDigit { .. } => write!(f, "expected a digit"),
+        }
+    }
+}
+
+impl From<ParseGridError> for Error {
+    fn from(err: ParseGridError) -> Self {
+        match err {
+            ParseGridError::Empty => Error::new(err.to_string()),
+            ParseGridError::RaggedRow { line } => Error::new(err.to_string()).at_line(line + 1),
+            ParseGridError::InvalidDigit { line, column } => Error::new(err.to_string())
+                .at_line(line + 1)
+                .at_column(column + 1),
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Result<Self, GridError> {
+        if cells.len() != width * height {
+            return Err(GridError::SizeMismatch {
+                expected: width * height,
+                actual: cells.len(),
+            });
+        }
+
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    pub fn index_of(&self, x: usize, y: usize) -> Option<usize> {
+        if x < self.width && y < self.height {
+            Some(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn coords_of(&self, index: usize) -> Option<(usize, usize)> {
+        if index < self.cells.len() {
+            Some((index % self.width, index / self.width))
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.index_of(x, y).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.index_of(x, y).map(move |index| &mut self.cells[index])
+    }
+
+    /// Indices of the orthogonally adjacent cells (up, right, down, left).
+    pub fn neighbours4(&self, index: usize) -> impl Iterator<Item = usize> + '_ {
+        self.neighbours(index, &[(0, -1), (1, 0), (0, 1), (-1, 0)])
+    }
+
+    /// Indices of the orthogonally and diagonally adjacent cells.
+    pub fn neighbours8(&self, index: usize) -> impl Iterator<Item = usize> + '_ {
+        self.neighbours(
+            index,
+            &[
+                (-1, -1),
+                (0, -1),
+                (1, -1),
+                (1, 0),
+                (1, 1),
+                (0, 1),
+                (-1, 1),
+                (-1, 0),
+            ],
+        )
+    }
+
+    fn neighbours(
+        &self,
+        index: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = usize> + '_ {
+        let coords = self.coords_of(index);
+
+        offsets.iter().filter_map(move |(dx, dy)| {
+            let (x, y) = coords?;
+
+            self.index_of(x.checked_add_signed(*dx)?, y.checked_add_signed(*dy)?)
+        })
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        if y < self.height {
+            Some(&self.cells[y * self.width..(y + 1) * self.width])
+        } else {
+            None
+        }
+    }
+
+    /// Every row from top to bottom, even empty ones on a grid without
+    /// columns.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).filter_map(move |y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> Option<impl Iterator<Item = &T>> {
+        if x < self.width {
+            Some(self.cells.iter().skip(x).step_by(self.width))
+        } else {
+            None
+        }
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).filter_map(move |x| self.column(x))
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+impl Grid<u8> {
+    /// Parses a grid made of one decimal digit per cell, one row per line.
+    pub fn parse_digits(s: &str) -> Result<Self, ParseGridError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for (line_index, line) in s.lines().enumerate() {
+            let row_width = line.chars().count();
+
+            if *width.get_or_insert(row_width) != row_width {
+                return Err(ParseGridError::RaggedRow { line: line_index });
+            }
+
+            for (column, c) in line.chars().enumerate() {
+                let digit = c.to_digit(10).ok_or(ParseGridError::InvalidDigit {
+                    line: line_index,
+                    column,
+                })?;
+
+                cells.push(digit as u8);
+            }
+
+            height += 1;
+        }
+
+        match width {
+            None | Some(0) => Err(ParseGridError::Empty),
+            Some(width) => Ok(Self {
+                width,
+                height,
+                cells,
+            }),
+        }
+    }
+}
+
+impl FromStr for Grid<u8> {
+    type Err = ParseGridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_digits(s)
+    }
+}
+
+impl<T> Index<usize> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.cells[index]
+    }
+}
+
+impl<T> IndexMut<usize> for Grid<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.cells[index]
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        self.get(x, y)
+            .unwrap_or_else(|| panic!("({}, {}) is out of the grid", x, y))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("({}, {}) is out of the grid", x, y))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+123
+456
+";
+
+    fn sorted(it: impl Iterator<Item = usize>) -> Vec<usize> {
+        let mut v: Vec<_> = it.collect();
+        v.sort_unstable();
+        v
+    }
+
+    #[test]
+    fn parse_digits() {
+        let grid: Grid<u8> = EXAMPLE.parse().unwrap();
+
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.cells(), &[1, 2, 3, 4, 5, 6]);
+        assert_eq!(grid.to_string(), EXAMPLE);
+
+        assert_eq!("".parse::<Grid<u8>>(), Err(ParseGridError::Empty));
+        assert_eq!(
+            "12\n345\n".parse::<Grid<u8>>(),
+            Err(ParseGridError::RaggedRow { line: 1 })
+        );
+        assert_eq!(
+            "12\n3x\n".parse::<Grid<u8>>(),
+            Err(ParseGridError::InvalidDigit { line: 1, column: 1 })
+        );
+    }
+
+    #[test]
+    fn new_checks_size() {
+        assert!(Grid::new(2, 2, vec![0; 4]).is_ok());
+        assert_eq!(
+            Grid::new(2, 2, vec![0; 5]),
+            Err(GridError::SizeMismatch {
+                expected: 4,
+                actual: 5
+            })
+        );
+    }
+
+    #[test]
+    fn coordinates() {
+        let grid: Grid<u8> = EXAMPLE.parse().unwrap();
+
+        assert_eq!(grid.index_of(2, 1), Some(5));
+        assert_eq!(grid.index_of(3, 0), None);
+        assert_eq!(grid.index_of(0, 2), None);
+        assert_eq!(grid.coords_of(4), Some((1, 1)));
+        assert_eq!(grid.coords_of(6), None);
+        assert_eq!(grid[(1, 1)], 5);
+        assert_eq!(grid[2], 3);
+    }
+
+    #[test]
+    fn neighbours_do_not_wrap() {
+        let grid = Grid::filled(3, 3, 0);
+
+        assert_eq!(sorted(grid.neighbours4(0)), vec![1, 3]);
+        assert_eq!(sorted(grid.neighbours4(2)), vec![1, 5]);
+        assert_eq!(sorted(grid.neighbours4(3)), vec![0, 4, 6]);
+        assert_eq!(sorted(grid.neighbours4(4)), vec![1, 3, 5, 7]);
+
+        assert_eq!(sorted(grid.neighbours8(0)), vec![1, 3, 4]);
+        assert_eq!(sorted(grid.neighbours8(5)), vec![1, 2, 4, 7, 8]);
+        assert_eq!(sorted(grid.neighbours8(4)), vec![0, 1, 2, 3, 5, 6, 7, 8]);
+
+        assert_eq!(grid.neighbours8(9).count(), 0);
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid: Grid<u8> = EXAMPLE.parse().unwrap();
+
+        assert_eq!(grid.row(1), Some(&[4, 5, 6][..]));
+        assert_eq!(grid.row(2), None);
+        assert_eq!(grid.rows().count(), 2);
+
+        let narrow: Grid<u8> = Grid::filled(0, 3, 0);
+
+        assert_eq!(narrow.row(0), Some(&[][..]));
+        assert_eq!(narrow.rows().count(), 3);
+        assert_eq!(narrow.columns().count(), 0);
+
+        assert_eq!(
+            grid.column(1).unwrap().copied().collect::<Vec<_>>(),
+            vec![2, 5]
+        );
+        assert!(grid.column(3).is_none());
+        assert_eq!(
+            grid.columns()
+                .map(|column| column.copied().collect::<Vec<_>>())
+                .collect::<Vec<_>>(),
+            vec![vec![1, 4], vec![2, 5], vec![3, 6]]
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use crate::days;
+use std::fmt::{Display, Formatter};
+use std::io::{self, Read};
+use std::path::PathBuf;
+
+/// Where a puzzle input is read from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    Path(PathBuf),
+    Stdin,
+    /// The `input.txt` file living next to the day's solution, embedded in
+    /// the binary.
+    Bundled(u8),
+}
+
+impl InputSource {
+    /// Interprets a command line argument: `-` stands for stdin, anything
+    /// else is a path. Without argument the bundled input of `day` is used.
+    pub fn from_arg(arg: Option<&str>, day: u8) -> Self {
+        match arg {
+            Some("-") => Self::Stdin,
+            Some(path) => Self::Path(path.into()),
+            None => Self::Bundled(day),
+        }
+    }
+
+    pub fn load(&self) -> Result<String, InputError> {
+        let result = match self {
+            Self::Path(path) => std::fs::read_to_string(path),
+            Self::Bundled(day) => days::get(*day)
+                .map(|solution| solution.input().to_string())
+                .ok_or_else(|| io::Error::from(io::ErrorKind::NotFound)),
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+        };
+
+        result.map_err(|err| InputError {
+            source: self.clone(),
+            err,
+        })
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Self::Path(path) => write!(f, "{}", path.display()),
+            Self::Stdin => write!(f, "stdin"),
+            Self::Bundled(day) => write!(f, "the bundled input of day {}", day),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    source: InputSource,
+    err: io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match (&self.source, self.err.kind()) {
+            (InputSource::Bundled(day), io::ErrorKind::NotFound) => write!(
+                f,
+                "no bundled input for day {}, pass the input path as argument",
+                day
+            ),
+            (_, io::ErrorKind::NotFound) => write!(f, "input file {} not found", self.source),
+            _ => write!(f, "could not read input from {}: {}", self.source, self.err),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn source_from_arg() {
+        assert_eq!(InputSource::from_arg(Some("-"), 4), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg(Some("my_input.txt"), 4),
+            InputSource::Path("my_input.txt".into())
+        );
+        assert_eq!(InputSource::from_arg(None, 4), InputSource::Bundled(4));
+    }
+
+    #[test]
+    fn load_bundled() {
+        assert_eq!(
+            InputSource::Bundled(1).load().unwrap(),
+            include_str!("days/day1/input.txt")
+        );
+    }
+
+    #[test]
+    fn load_missing_file() {
+        let err = InputSource::Path("does/not/exist.txt".into())
+            .load()
+            .unwrap_err();
+
+        assert_eq!(err.to_string(), "input file does/not/exist.txt not found");
+
+        let err = InputSource::Bundled(26).load().unwrap_err();
+
+        assert!(err.to_string().starts_with("no bundled input for day 26"));
+    }
+}
@@ -0,0 +1,56 @@
+//! Minimal JSON encoding of the results, enough for the runner's one record
+//! per line output.
+
+use crate::solution::Answer;
+use std::fmt::Write;
+
+/// Encodes `value` as a JSON string literal.
+pub fn string(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len() + 2);
+
+    encoded.push('"');
+
+    for c in value.chars() {
+        match c {
+            '"' => encoded.push_str("\\\""),
+            '\\' => encoded.push_str("\\\\"),
+            '\n' => encoded.push_str("\\n"),
+            '\r' => encoded.push_str("\\r"),
+            '\t' => encoded.push_str("\\t"),
+            c if c.is_control() => write!(encoded, "\\u{:04x}", c as u32).unwrap(),
+            c => encoded.push(c),
+        }
+    }
+
+    encoded.push('"');
+
+    encoded
+}
+
+/// Numbers are kept as numbers, text as strings and unsolved parts are `null`.
+pub fn answer(answer: &Answer) -> String {
+    match answer {
+        Answer::Number(n) => n.to_string(),
+        Answer::Text(text) => string(text),
+        Answer::Unsolved => "null".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_string() {
+        assert_eq!(string("abc"), r##""abc""##);
+        assert_eq!(string("# #\n\"a\"\\"), r##""# #\n\"a\"\\""##);
+        assert_eq!(string("\u{1}"), r##""\u0001""##);
+    }
+
+    #[test]
+    fn encode_answer() {
+        assert_eq!(answer(&Answer::Number(-42)), "-42");
+        assert_eq!(answer(&Answer::Text("a\nb".into())), r##""a\nb""##);
+        assert_eq!(answer(&Answer::Unsolved), "null");
+    }
+}
@@ -0,0 +1,10 @@
+pub mod answers;
+pub mod bench;
+pub mod days;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod json;
+pub mod optimize;
+pub mod rng;
+pub mod solution;
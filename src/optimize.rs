@@ -0,0 +1,135 @@
+//! Minimization of convex functions over a range of integers.
+
+use std::ops::RangeInclusive;
+
+/// Where a function reaches its minimum, and how many times it was
+/// evaluated to find it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Minimum {
+    pub argument: i64,
+    pub value: i64,
+    pub evaluations: usize,
+}
+
+/// Ternary search for the minimum of `f` over `range`, which is `None` when
+/// the range is empty.
+///
+/// `f` must be convex over the range, plateaus included: the range shrinks
+/// by at least a third at each step so the search always ends, after
+/// `O(log n)` evaluations. On a function that is not convex, it still ends
+/// but may miss the minimum.
+pub fn minimize<F>(range: RangeInclusive<i64>, f: F) -> Option<Minimum>
+where
+    F: Fn(i64) -> i64,
+{
+    let (mut low, mut high) = range.into_inner();
+
+    if low > high {
+        return None;
+    }
+
+    let mut evaluations = 0;
+    let mut eval = |x| {
+        evaluations += 1;
+        f(x)
+    };
+
+    // The width of the range may not fit an i64, but its third does.
+    while high.abs_diff(low) > 2 {
+        let third = (high.abs_diff(low) / 3) as i64;
+        let (left, right) = (low + third, high - third);
+        let (left_value, right_value) = (eval(left), eval(right));
+
+        // When both values are equal, convexity puts a minimum between them.
+        if left_value <= right_value {
+            high = if left_value < right_value {
+                right - 1
+            } else {
+                right
+            };
+        }
+
+        if left_value >= right_value {
+            low = if left_value > right_value {
+                left + 1
+            } else {
+                left
+            };
+        }
+    }
+
+    let (argument, value) = (low..=high)
+        .map(|x| (x, eval(x)))
+        .min_by_key(|(_, value)| *value)
+        .expect("non empty range");
+
+    Some(Minimum {
+        argument,
+        value,
+        evaluations,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parabola() {
+        let minimum = minimize(-1000..=1000, |x| (x - 17) * (x - 17) + 3).unwrap();
+
+        assert_eq!((minimum.argument, minimum.value), (17, 3));
+    }
+
+    #[test]
+    fn plateaus() {
+        let minimum = minimize(-100..=100, |x| (x.abs() - 10).max(0)).unwrap();
+
+        assert_eq!(minimum.value, 0);
+        assert!((-10..=10).contains(&minimum.argument));
+
+        assert_eq!(minimize(0..=1_000_000, |_| 42).unwrap().value, 42);
+    }
+
+    #[test]
+    fn bounds() {
+        assert_eq!(
+            minimize(5..=5, |x| x),
+            Some(Minimum {
+                argument: 5,
+                value: 5,
+                evaluations: 1
+            })
+        );
+        assert_eq!(minimize(0..=1000, |x| x).unwrap().argument, 0);
+        assert_eq!(minimize(0..=1000, |x| -x).unwrap().argument, 1000);
+        assert_eq!(minimize(RangeInclusive::new(1, 0), |x| x), None);
+    }
+
+    #[test]
+    fn full_range() {
+        let minimum = minimize(i64::MIN..=i64::MAX, |x| (x / 2 - 1000).abs()).unwrap();
+
+        assert_eq!(minimum.value, 0);
+        assert_eq!(
+            minimize(i64::MIN..=i64::MAX, |x| x).unwrap().argument,
+            i64::MIN
+        );
+        assert!(
+            minimize(i64::MIN..=i64::MAX, |x| -(x / 2))
+                .unwrap()
+                .argument
+                >= i64::MAX - 1
+        );
+    }
+
+    #[test]
+    fn logarithmic_evaluations() {
+        let minimum = minimize(0..=1_000_000, |x| (x - 123_456).abs()).unwrap();
+
+        assert_eq!(minimum.argument, 123_456);
+        // Two evaluations per step, each one keeping two thirds of the range
+        // at most, and three for the last points.
+        assert!(minimum.evaluations <= 2 * 35 + 3, "{}", minimum.evaluations);
+    }
+}
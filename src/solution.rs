@@ -0,0 +1,197 @@
+use crate::bench::{self, Measure};
+use crate::error;
+use num_bigint::BigUint;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// A day of the calendar: how to parse its input and solve both parts.
+pub trait Solution {
+    const DAY: u8;
+    /// The puzzle input living next to the day's sources, embedded in the
+    /// binary.
+    const INPUT: &'static str;
+    /// Known answers for [`Self::INPUT`], in the format of
+    /// [`crate::answers`].
+    const ANSWERS: &'static str;
+
+    type Input;
+
+    fn parse(input: &str) -> error::Result<Self::Input>;
+    fn part1(input: &Self::Input) -> error::Result<Answer>;
+
+    fn part2(_input: &Self::Input) -> error::Result<Answer> {
+        Ok(Answer::Unsolved)
+    }
+
+    /// Problems of an input that can still be solved, such as departures from
+    /// the rules of the puzzle, reported as warnings.
+    fn warnings(_input: &Self::Input) -> Vec<error::Error> {
+        Vec::new()
+    }
+
+    /// Alternative algorithms for the parts, compared against `part1` and
+    /// `part2` when benchmarking.
+    fn variants() -> Vec<Variant<Self::Input>> {
+        Vec::new()
+    }
+}
+
+pub struct Variant<I> {
+    pub part: Part,
+    pub name: &'static str,
+    pub solve: fn(&I) -> error::Result<Answer>,
+}
+
+/// Object safe view over a [`Solution`], so that days can be stored together
+/// and dispatched on at runtime.
+pub trait DynSolution {
+    fn day(&self) -> u8;
+    fn input(&self) -> &'static str;
+    fn answers(&self) -> &'static str;
+    /// Parses `input` once and solves each of `parts`, errors being tagged
+    /// with the day. Parsing errors abort the run while each part gets its
+    /// own result.
+    fn run(&self, input: &str, parts: &[Part]) -> error::Result<Vec<PartRun>>;
+    /// Parses `input` and lists its warnings, tagged with the day.
+    fn warnings(&self, input: &str) -> error::Result<Vec<error::Error>>;
+    fn bench(&self, input: &str, runs: usize) -> error::Result<Vec<Measure>>;
+}
+
+impl<S: Solution> DynSolution for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn input(&self) -> &'static str {
+        S::INPUT
+    }
+
+    fn answers(&self) -> &'static str {
+        S::ANSWERS
+    }
+
+    fn run(&self, input: &str, parts: &[Part]) -> error::Result<Vec<PartRun>> {
+        let input = S::parse(input).map_err(|err| err.in_day(S::DAY))?;
+
+        Ok(parts
+            .iter()
+            .map(|part| {
+                let start = Instant::now();
+                let answer = match part {
+                    Part::One => S::part1(&input),
+                    Part::Two => S::part2(&input),
+                };
+
+                PartRun {
+                    part: *part,
+                    answer: answer.map_err(|err| err.in_day(S::DAY)),
+                    elapsed: start.elapsed(),
+                }
+            })
+            .collect())
+    }
+
+    fn warnings(&self, input: &str) -> error::Result<Vec<error::Error>> {
+        let input = S::parse(input).map_err(|err| err.in_day(S::DAY))?;
+
+        Ok(S::warnings(&input)
+            .into_iter()
+            .map(|warning| warning.in_day(S::DAY))
+            .collect())
+    }
+
+    fn bench(&self, input: &str, runs: usize) -> error::Result<Vec<Measure>> {
+        bench::bench::<S>(input, runs).map_err(|err| err.in_day(S::DAY))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Self::One => write!(f, "1"),
+            Self::Two => write!(f, "2"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ParsePartError;
+
+impl FromStr for Part {
+    type Err = ParsePartError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Self::One),
+            "2" => Ok(Self::Two),
+            _ => Err(ParsePartError),
+        }
+    }
+}
+
+/// Result of solving one part, along with the time spent solving it, parsing
+/// excluded.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PartRun {
+    pub part: Part,
+    pub answer: error::Result<Answer>,
+    pub elapsed: Duration,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    Unsolved,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Self::Number(n) => write!(f, "{}", n),
+            Self::Text(text) => write!(f, "{}", text),
+            Self::Unsolved => write!(f, "(not solved yet)"),
+        }
+    }
+}
+
+macro_rules! impl_answer_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Self::Number(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from_number!(i32, i64, u32, u64, usize);
+
+/// Numbers too large for an `i128` are kept as text.
+impl From<BigUint> for Answer {
+    fn from(n: BigUint) -> Self {
+        match i128::try_from(&n) {
+            Ok(n) => Self::Number(n),
+            Err(_) => Self::Text(n.to_string()),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}